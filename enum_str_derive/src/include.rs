@@ -0,0 +1,171 @@
+//! `include_enum_str!`, which reads the variants of an enum from a data file.
+
+use std::path::PathBuf;
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use serde_json::{Map, Value};
+use syn::parse::{Parse, ParseStream};
+use syn::{Attribute, Error, Ident, LitStr, Token, Visibility};
+
+/// The input of `include_enum_str!`: the head of an `enum_str!` invocation and the path of the
+/// data file.
+pub struct Input {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    ident: Ident,
+    path: LitStr,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let ident = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let path = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Input {
+            attrs,
+            vis,
+            ident,
+            path,
+        })
+    }
+}
+
+/// A variant read from the data file: its identifier, value and aliases.
+struct Entry {
+    key: String,
+    values: Vec<String>,
+}
+
+pub fn expand(input: Input) -> syn::Result<TokenStream2> {
+    let Input {
+        attrs,
+        vis,
+        ident,
+        path,
+    } = input;
+
+    let dir = std::env::var_os("CARGO_MANIFEST_DIR")
+        .ok_or_else(|| Error::new_spanned(&path, "CARGO_MANIFEST_DIR is not set"))?;
+    let full = PathBuf::from(dir).join(path.value());
+    let data = std::fs::read_to_string(&full).map_err(|err| {
+        Error::new_spanned(&path, format!("cannot read `{}`: {err}", full.display()))
+    })?;
+
+    let entries = match full.extension().and_then(|ext| ext.to_str()) {
+        Some("json") => parse_json(&data),
+        Some("csv") => parse_csv(&data),
+        _ => Err("expected a `.json` or `.csv` file".to_owned()),
+    }
+    .map_err(|msg| Error::new_spanned(&path, format!("{}: {msg}", path.value())))?;
+
+    let mut variants = Vec::new();
+    for Entry { key, values } in entries {
+        let key = syn::parse_str::<Ident>(&key).map_err(|_| {
+            Error::new_spanned(&path, format!("`{key}` is not a valid variant name"))
+        })?;
+        let value = &values[0];
+        let aliases = &values[1..];
+        let aliases = (!aliases.is_empty()).then(|| quote!(, aliases = [#(#aliases),*]));
+        variants.push(quote!((#key, #value #aliases),));
+    }
+
+    // Including the file makes cargo rebuild the crate when it changes.
+    let full = full.to_string_lossy();
+    Ok(quote! {
+        const _: &[u8] = ::core::include_bytes!(#full);
+
+        ::enum_str::enum_str! {
+            #(#attrs)*
+            #vis #ident,
+            #(#variants)*
+        }
+    })
+}
+
+/// Reads an object mapping every variant name to its value, or to an array of its value and
+/// aliases.
+fn parse_json(data: &str) -> Result<Vec<Entry>, String> {
+    let map: Map<String, Value> = serde_json::from_str(data).map_err(|err| err.to_string())?;
+    map.into_iter()
+        .map(|(key, value)| {
+            let values = match value {
+                Value::String(value) => vec![value],
+                Value::Array(values) if !values.is_empty() => values
+                    .into_iter()
+                    .map(|value| match value {
+                        Value::String(value) => Ok(value),
+                        _ => Err(format!("the aliases of `{key}` must be strings")),
+                    })
+                    .collect::<Result<_, _>>()?,
+                _ => {
+                    return Err(format!(
+                        "the value of `{key}` must be a string or a non-empty array of strings"
+                    ))
+                }
+            };
+            Ok(Entry { key, values })
+        })
+        .collect()
+}
+
+/// Reads one variant per line, as its name, its value and then its aliases, separated by
+/// commas. Fields can be quoted with `"` to contain commas, with `""` for a quote.
+fn parse_csv(data: &str) -> Result<Vec<Entry>, String> {
+    let mut entries = Vec::new();
+    for (number, line) in data.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let mut fields = csv_fields(line).map_err(|msg| format!("line {}: {msg}", number + 1))?;
+        if fields.len() < 2 {
+            return Err(format!("line {}: expected a name and a value", number + 1));
+        }
+        let key = fields.remove(0).trim().to_owned();
+        entries.push(Entry {
+            key,
+            values: fields,
+        });
+    }
+    Ok(entries)
+}
+
+fn csv_fields(line: &str) -> Result<Vec<String>, String> {
+    let mut fields = Vec::new();
+    let mut chars = line.chars().peekable();
+    loop {
+        let mut field = String::new();
+        if chars.peek() == Some(&'"') {
+            chars.next();
+            loop {
+                match chars.next() {
+                    Some('"') if chars.peek() == Some(&'"') => {
+                        chars.next();
+                        field.push('"');
+                    }
+                    Some('"') => break,
+                    Some(c) => field.push(c),
+                    None => return Err("unterminated quoted field".to_owned()),
+                }
+            }
+            if !matches!(chars.peek(), None | Some(',')) {
+                return Err("expected a `,` after a quoted field".to_owned());
+            }
+        } else {
+            while let Some(&c) = chars.peek() {
+                if c == ',' {
+                    break;
+                }
+                field.push(c);
+                chars.next();
+            }
+        }
+        fields.push(field);
+        if chars.next().is_none() {
+            return Ok(fields);
+        }
+    }
+}
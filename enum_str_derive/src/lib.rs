@@ -0,0 +1,459 @@
+//! Derive and attribute macros for [`enum_str`](https://docs.rs/enum_str).
+//!
+//! Use them through the `derive` feature of `enum_str`, which re-exports [`EnumStr`],
+//! the [`macro@enum_str`] attribute and [`include_enum_str!`].
+
+mod include;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::meta::ParseNestedMeta;
+use syn::parse::ParseStream;
+use syn::{
+    bracketed, parenthesized, parse_macro_input, Attribute, Data, DeriveInput, Error, Expr, Fields,
+    Ident, ItemEnum, Lit, LitStr, Meta, Path, Token, Type, Variant, Visibility,
+};
+
+/// Generates the same `as_str`, `Display` and `FromStr` surface as `enum_str!` for an enum
+/// of unit variants.
+///
+/// Every variant needs an `#[enum_str("value")]` attribute, which may also list aliases and
+/// mark the default variant: `#[enum_str("🍎", aliases = ["apple"], default)]`. Options go in
+/// an `#[enum_str(...)]` attribute on the enum:
+/// - `case_insensitive` parses values regardless of case.
+/// - `trim` and `trim(collapse)` normalize whitespace in the input.
+/// - `eq_str` implements `PartialEq` against `str`.
+/// - `borrow_str` implements `Borrow<str>` and a matching `Hash`.
+/// - `debug_str` implements a `Debug` that prints the value next to the identifier. Leave
+///   `Debug` out of the derives.
+/// - `phf` parses through a perfect hash table, with the `phf` feature of `enum_str`.
+/// - `binary_search` parses by searching a sorted table.
+/// - `hash_map` parses through a `HashMap` built on first use, with the `std` feature.
+/// - `nfc` compares the NFC forms of the input and the values, with the
+///   `unicode-normalization` feature.
+/// - `suggest` names the closest value in parse errors.
+/// - `serde(names)` lets `Deserialize` accept the names of the variants too.
+/// - `serde(other = Unknown)` deserializes unmatched strings as `Unknown`.
+/// - `serde(module = fruit_str)` adds a module for `#[serde(with = "fruit_str")]`.
+/// - `map(FruitMap)` declares an array-backed map keyed by the variants.
+/// - `set(FruitSet)` or `set(FruitSet, u8)` declares a bitset of them.
+/// - `skip(Display, ...)` leaves out the listed trait impls.
+/// - `rename(as_str = as_code)` gives `as_str` another name, and `rename(parse = ...)` the
+///   `parse` function of the `nom` feature.
+/// - `vis(as_str = pub, ...)` sets the visibility of single generated methods and constants.
+/// - `error(MyError)` and `error(MyError, with = MyError::unknown)` change the error type of
+///   `FromStr`, as described for `enum_str!`.
+///
+/// An integer `#[repr(...)]` on the enum adds `as_<int>` and `try_from_<int>` as well.
+#[proc_macro_derive(EnumStr, attributes(enum_str))]
+pub fn derive_enum_str(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_derive(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Adds the `enum_str!` surface to an existing enum of unit variants.
+///
+/// Every variant needs a `#[str = "value"]` attribute, which is removed from the enum. The
+/// same options as for the derive can be passed to the attribute, e.g.
+/// `#[enum_str(case_insensitive)]`.
+#[proc_macro_attribute]
+pub fn enum_str(args: TokenStream, input: TokenStream) -> TokenStream {
+    let mut opts = Vec::new();
+    let parser = syn::meta::parser(|meta| parse_option(meta, &mut opts));
+    parse_macro_input!(args with parser);
+
+    let input = parse_macro_input!(input as ItemEnum);
+    expand_attribute(input, opts)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Declares an enum with `enum_str!`, reading its variants from a data file.
+///
+/// The input is the head of an `enum_str!` invocation followed by the path of the file,
+/// relative to the directory of the crate's `Cargo.toml`:
+/// `include_enum_str!(#[case_insensitive] pub Currency, "data/currency.json")`. A `.json` file
+/// holds an object mapping each variant name to its value, or to an array of its value and
+/// aliases. A `.csv` file has one variant per line, as its name, its value and then its
+/// aliases. The variants keep the order of the file, and the crate is rebuilt when it changes.
+#[proc_macro]
+pub fn include_enum_str(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as include::Input);
+    include::expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_derive(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let DeriveInput {
+        attrs,
+        vis,
+        ident,
+        generics,
+        data,
+    } = input;
+
+    if !generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            generics,
+            "EnumStr cannot be derived for generic enums",
+        ));
+    }
+
+    let data = match data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(Error::new_spanned(
+                ident,
+                "EnumStr can only be derived for enums",
+            ))
+        }
+    };
+
+    let mut opts = repr_option(&attrs)?;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("enum_str")) {
+        attr.parse_nested_meta(|meta| parse_option(meta, &mut opts))?;
+    }
+
+    let mut variants = Vec::new();
+    for variant in &data.variants {
+        check_unit(variant)?;
+
+        let attr = variant
+            .attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("enum_str"))
+            .ok_or_else(|| Error::new_spanned(variant, "missing #[enum_str(\"...\")] attribute"))?;
+        let args = attr.parse_args_with(parse_variant_args)?;
+        variants.push(variant_tokens(variant, &args)?);
+    }
+
+    Ok(impl_tokens(&vis, &ident, &opts, &variants))
+}
+
+fn expand_attribute(mut input: ItemEnum, mut opts: Vec<TokenStream2>) -> syn::Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "#[enum_str] cannot be used on generic enums",
+        ));
+    }
+    opts.extend(repr_option(&input.attrs)?);
+
+    let mut variants = Vec::new();
+    for variant in &mut input.variants {
+        check_unit(variant)?;
+
+        let index = variant
+            .attrs
+            .iter()
+            .position(|attr| attr.path().is_ident("str"))
+            .ok_or_else(|| Error::new_spanned(&*variant, "missing #[str = \"...\"] attribute"))?;
+        let value = parse_str_attr(&variant.attrs.remove(index))?;
+        let args = VariantArgs {
+            value,
+            aliases: Vec::new(),
+            default: false,
+        };
+        variants.push(variant_tokens(variant, &args)?);
+    }
+
+    let impls = impl_tokens(&input.vis, &input.ident, &opts, &variants);
+    Ok(quote! {
+        #input
+        #impls
+    })
+}
+
+/// Parses an enum level option shared by the derive and the attribute.
+fn parse_option(meta: ParseNestedMeta, opts: &mut Vec<TokenStream2>) -> syn::Result<()> {
+    if meta.path.is_ident("case_insensitive") {
+        opts.push(quote!({ case_insensitive }));
+        Ok(())
+    } else if meta.path.is_ident("eq_str") {
+        opts.push(quote!({ eq_str }));
+        Ok(())
+    } else if meta.path.is_ident("debug_str") {
+        opts.push(quote!({ debug_str }));
+        Ok(())
+    } else if meta.path.is_ident("borrow_str") {
+        opts.push(quote!({ borrow_str }));
+        Ok(())
+    } else if meta.path.is_ident("trim") {
+        opts.push(quote!({ trim }));
+        if meta.input.peek(syn::token::Paren) {
+            meta.parse_nested_meta(|meta| {
+                if meta.path.is_ident("collapse") {
+                    opts.push(quote!({ collapse }));
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `collapse`"))
+                }
+            })?;
+        }
+        Ok(())
+    } else if meta.path.is_ident("binary_search") {
+        opts.push(quote!({ binary_search }));
+        Ok(())
+    } else if meta.path.is_ident("hash_map") {
+        opts.push(quote!({ hash_map }));
+        Ok(())
+    } else if meta.path.is_ident("phf") {
+        opts.push(quote!({ phf }));
+        Ok(())
+    } else if meta.path.is_ident("map") {
+        let content;
+        parenthesized!(content in meta.input);
+        let map: Ident = content.parse()?;
+        opts.push(quote!({ map(#map) }));
+        Ok(())
+    } else if meta.path.is_ident("set") {
+        let content;
+        parenthesized!(content in meta.input);
+        let set: Ident = content.parse()?;
+        let int = if content.parse::<Option<Token![,]>>()?.is_some() && !content.is_empty() {
+            content.parse()?
+        } else {
+            Ident::new("u64", set.span())
+        };
+        opts.push(quote!({ set(#set, #int) }));
+        Ok(())
+    } else if meta.path.is_ident("nfc") {
+        opts.push(quote!({ nfc }));
+        Ok(())
+    } else if meta.path.is_ident("suggest") {
+        opts.push(quote!({ suggest }));
+        Ok(())
+    } else if meta.path.is_ident("serde") {
+        meta.parse_nested_meta(|meta| {
+            if meta.path.is_ident("names") {
+                opts.push(quote!({ serde(names) }));
+                Ok(())
+            } else if meta.path.is_ident("other") {
+                let other: Ident = meta.value()?.parse()?;
+                opts.push(quote!({ serde(other = #other) }));
+                Ok(())
+            } else if meta.path.is_ident("module") {
+                let module: Ident = meta.value()?.parse()?;
+                opts.push(quote!({ serde(module = #module) }));
+                Ok(())
+            } else {
+                Err(meta.error("expected `names`, `other = Variant` or `module = name`"))
+            }
+        })
+    } else if meta.path.is_ident("rename") {
+        meta.parse_nested_meta(|meta| {
+            if meta.path.is_ident("as_str") {
+                let to: Ident = meta.value()?.parse()?;
+                opts.push(quote!({ rename(as_str = #to) }));
+                Ok(())
+            } else if meta.path.is_ident("parse") {
+                let to: Ident = meta.value()?.parse()?;
+                opts.push(quote!({ rename(parse = #to) }));
+                Ok(())
+            } else {
+                Err(meta.error("expected `as_str = new_name` or `parse = new_name`"))
+            }
+        })
+    } else if meta.path.is_ident("error") {
+        let content;
+        parenthesized!(content in meta.input);
+        let err: Type = content.parse()?;
+        let mut with = None;
+        if content.parse::<Option<Token![,]>>()?.is_some() && !content.is_empty() {
+            let name: Ident = content.parse()?;
+            if name != "with" {
+                return Err(Error::new_spanned(name, "expected `with = path`"));
+            }
+            content.parse::<Token![=]>()?;
+            let path: Path = content.parse()?;
+            with = Some(quote!(, with = #path));
+        }
+        opts.push(quote!({ error(#err #with) }));
+        Ok(())
+    } else if meta.path.is_ident("vis") {
+        const ITEMS: &[&str] = &[
+            "VARIANTS",
+            "VALUES",
+            "TABLE",
+            "COUNT",
+            "MAX_STR_LEN",
+            "MIN_STR_LEN",
+            "PATTERN",
+            "as_str",
+            "as_bytes",
+            "as_short_str",
+            "as_long_str",
+            "name",
+            "from_name",
+            "from_str_ignore_ascii_case",
+            "is_valid",
+            "from_str_unchecked",
+            "strip_prefix",
+            "match_longest_at",
+            "parse",
+            "find_all",
+            "code",
+            "from_code",
+            "from_env",
+            "parse_list",
+            "join",
+            "mapping",
+            "index",
+            "from_index",
+            "next",
+            "prev",
+            "next_wrapping",
+            "prev_wrapping",
+            "cmp_str",
+            "sorted_variants",
+            "as_str_locale",
+            "from_str_any_locale",
+            "iter",
+        ];
+        meta.parse_nested_meta(|meta| match meta.path.get_ident() {
+            Some(ident) if ITEMS.iter().any(|item| ident == item) => {
+                let vis: Visibility = meta.value()?.parse()?;
+                opts.push(quote!({ vis(#ident = #vis) }));
+                Ok(())
+            }
+            _ => Err(meta.error(format!("expected one of {}", ITEMS.join(", ")))),
+        })
+    } else if meta.path.is_ident("skip") {
+        const SKIPPABLE: &[&str] = &[
+            "Display",
+            "FromStr",
+            "AsRef",
+            "From",
+            "TryFrom",
+            "Serialize",
+            "Deserialize",
+        ];
+        meta.parse_nested_meta(|meta| match meta.path.get_ident() {
+            Some(ident) if SKIPPABLE.iter().any(|name| ident == name) => {
+                opts.push(quote!({ skip(#ident) }));
+                Ok(())
+            }
+            _ => Err(meta.error(format!("expected one of {}", SKIPPABLE.join(", ")))),
+        })
+    } else {
+        Err(meta.error("unsupported enum_str option"))
+    }
+}
+
+/// Passes an integer `#[repr(...)]` on to the generated impls as a `{repr(...)}` option.
+fn repr_option(attrs: &[Attribute]) -> syn::Result<Vec<TokenStream2>> {
+    const INTEGERS: &[&str] = &[
+        "u8", "u16", "u32", "u64", "usize", "i8", "i16", "i32", "i64", "isize",
+    ];
+
+    let mut opts = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            if let Some(ident) = meta.path.get_ident() {
+                if INTEGERS.iter().any(|int| ident == int) {
+                    opts.push(quote!({ repr(#ident) }));
+                }
+            }
+            Ok(())
+        })?;
+    }
+    Ok(opts)
+}
+
+fn check_unit(variant: &Variant) -> syn::Result<()> {
+    match variant.fields {
+        Fields::Unit => Ok(()),
+        _ => Err(Error::new_spanned(
+            variant,
+            "enum_str only supports unit variants",
+        )),
+    }
+}
+
+/// The arguments of a variant's `#[enum_str(...)]` attribute.
+struct VariantArgs {
+    value: LitStr,
+    aliases: Vec<LitStr>,
+    default: bool,
+}
+
+/// Parses `"value"` optionally followed by `, aliases = ["a", "b"]` and `, default`.
+fn parse_variant_args(input: ParseStream) -> syn::Result<VariantArgs> {
+    let mut args = VariantArgs {
+        value: input.parse()?,
+        aliases: Vec::new(),
+        default: false,
+    };
+
+    while input.parse::<Option<Token![,]>>()?.is_some() {
+        if input.is_empty() {
+            break;
+        }
+
+        let name: Ident = input.parse()?;
+        if name == "default" {
+            args.default = true;
+            continue;
+        }
+        if name != "aliases" {
+            return Err(Error::new_spanned(name, "expected `aliases` or `default`"));
+        }
+        input.parse::<Token![=]>()?;
+
+        let content;
+        bracketed!(content in input);
+        args.aliases.extend(
+            content.parse_terminated(|input: ParseStream| input.parse::<LitStr>(), Token![,])?,
+        );
+    }
+
+    Ok(args)
+}
+
+/// Parses `#[str = "value"]`.
+fn parse_str_attr(attr: &Attribute) -> syn::Result<LitStr> {
+    if let Meta::NameValue(meta) = &attr.meta {
+        if let Expr::Lit(expr) = &meta.value {
+            if let Lit::Str(value) = &expr.lit {
+                return Ok(value.clone());
+            }
+        }
+    }
+
+    Err(Error::new_spanned(attr, "expected #[str = \"...\"]"))
+}
+
+/// Emits a variant for `__enum_str_impl!`, behind the combined `cfg` attributes of the variant.
+fn variant_tokens(variant: &Variant, args: &VariantArgs) -> syn::Result<TokenStream2> {
+    let preds = variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("cfg"))
+        .map(|attr| attr.parse_args::<TokenStream2>())
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let key = &variant.ident;
+    let value = &args.value;
+    let aliases = &args.aliases;
+    let aliases = (!aliases.is_empty()).then(|| quote!(, aliases = [#(#aliases),*]));
+    let default = args.default.then(|| quote!(, default));
+    Ok(quote!(#[cfg(all(#(#preds),*))] (#key, #value #aliases #default),))
+}
+
+fn impl_tokens(
+    vis: &Visibility,
+    ident: &Ident,
+    opts: &[TokenStream2],
+    variants: &[TokenStream2],
+) -> TokenStream2 {
+    quote! {
+        ::enum_str::__enum_str_impl! {
+            [#(#opts)*] #vis #ident,
+            #(#variants)*
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use enum_str_derive::enum_str;
+use std::str::FromStr;
+
+#[enum_str]
+#[derive(Debug, Clone, PartialEq)]
+enum Fruit {
+    #[str = "🍎"]
+    Apple,
+    /// Doc comments are kept.
+    #[str = "🍍"]
+    Pineapple,
+}
+
+#[enum_str(case_insensitive)]
+#[derive(Debug, Clone, PartialEq)]
+#[repr(u8)]
+pub enum Method {
+    #[str = "GET"]
+    Get = 1,
+    #[str = "POST"]
+    Post = 2,
+    #[cfg(any())]
+    #[str = "PUT"]
+    Put = 3,
+}
+
+#[test]
+fn test_as_str() {
+    assert_eq!("🍎", Fruit::Apple.as_str());
+    assert_eq!("🍍", Fruit::Pineapple.to_string());
+}
+
+#[test]
+fn test_from_str() {
+    assert_eq!(Fruit::Pineapple, Fruit::from_str("🍍").unwrap());
+    assert!(Fruit::from_str("🍌").is_err());
+    assert_eq!(Method::Post, "post".parse().unwrap());
+}
+
+#[test]
+fn test_enum_is_kept() {
+    assert_eq!(2, Method::Post as u8);
+    assert_eq!(2, Method::Post.as_u8());
+    assert_eq!(Some(Method::Get), Method::try_from_u8(1));
+}
+
+#[test]
+fn test_cfg_variant() {
+    assert_eq!(None, Method::try_from_u8(3));
+    assert!(Method::from_str("put").is_err());
+    assert_eq!(&["GET", "POST"], Method::VALUES);
+}
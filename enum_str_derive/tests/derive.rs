@@ -0,0 +1,82 @@
+use enum_str_derive::EnumStr;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, EnumStr)]
+#[enum_str(map(FruitMap), set(FruitSet, u8))]
+enum Fruit {
+    #[enum_str("🍎", aliases = ["apple"])]
+    Apple,
+    #[enum_str("🍍", default)]
+    Pineapple,
+    /// Doc comments are left alone.
+    #[enum_str("🍓")]
+    Strawberry,
+}
+
+#[derive(Debug, Clone, PartialEq, EnumStr)]
+#[enum_str(case_insensitive, trim(collapse), eq_str)]
+pub enum Method {
+    #[enum_str("GET")]
+    Get,
+    #[enum_str("POST")]
+    Post,
+}
+
+#[derive(Clone, Copy, EnumStr)]
+#[enum_str(debug_str)]
+enum Glyph {
+    #[enum_str("U+2713")]
+    Check,
+}
+
+#[test]
+fn test_as_str() {
+    assert_eq!("🍎", Fruit::Apple.as_str());
+    assert_eq!("🍓", Fruit::Strawberry.to_string());
+}
+
+#[test]
+fn test_from_str() {
+    assert_eq!(Fruit::Pineapple, Fruit::from_str("🍍").unwrap());
+    assert_eq!(Fruit::Apple, Fruit::from_str("apple").unwrap());
+    assert!(Fruit::from_str("🍌").is_err());
+}
+
+#[test]
+fn test_default() {
+    assert_eq!(Fruit::Pineapple, Fruit::default());
+}
+
+#[test]
+fn test_case_insensitive() {
+    assert_eq!(Method::Post, Method::from_str("post").unwrap());
+    assert_eq!(Method::Get, Method::from_str("Get").unwrap());
+    assert_eq!(Method::Get, Method::from_str(" get ").unwrap());
+    assert!(Method::Get == "GET");
+}
+
+#[test]
+fn test_variants() {
+    assert_eq!(3, Fruit::COUNT);
+    assert_eq!(&["GET", "POST"], Method::VALUES);
+}
+
+#[test]
+fn test_map() {
+    let mut prices = FruitMap::from_fn(|_| 2);
+    prices[Fruit::Strawberry] = 4;
+    assert_eq!(&2, prices.get(&Fruit::Apple));
+    assert_eq!(8, prices.iter().map(|(_, price)| price).sum::<i32>());
+}
+
+#[test]
+fn test_set() {
+    let set: FruitSet = "🍓,apple".parse().unwrap();
+    assert_eq!(0b101u8, set.bits());
+    assert_eq!("🍎,🍓", set.to_string());
+}
+
+#[test]
+fn test_debug_str() {
+    assert_eq!(r#"Check("U+2713")"#, format!("{:?}", Glyph::Check));
+}
@@ -0,0 +1,35 @@
+use enum_str_derive::include_enum_str;
+use std::str::FromStr;
+
+include_enum_str!(
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub Currency,
+    "tests/data/currency.json"
+);
+
+include_enum_str!(
+    #[case_insensitive]
+    Country,
+    "tests/data/country.csv"
+);
+
+#[test]
+fn test_json() {
+    assert_eq!(&["USD", "EUR", "JPY"], Currency::VALUES);
+    assert_eq!("EUR", Currency::Eur.as_str());
+    assert_eq!(Ok(Currency::Usd), Currency::from_str("$"));
+}
+
+#[test]
+fn test_csv() {
+    assert_eq!(&["CH", "FR", "DE"], Country::VALUES);
+    assert_eq!(Ok(Country::Fr), Country::from_str("fr"));
+    assert_eq!(
+        Ok(Country::De),
+        Country::from_str("Deutschland, Bundesrepublik")
+    );
+    assert_eq!(
+        Ok(Country::Ch),
+        Country::from_str("Confoederatio Helvetica")
+    );
+}
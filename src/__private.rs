@@ -0,0 +1,390 @@
+//! Support code for the macros. Not public API.
+
+#[cfg(feature = "alloc")]
+pub use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+pub use std::{collections::HashMap, ffi::OsStr, sync::OnceLock};
+
+/// Compares two strings, optionally after case folding every character and mapping every run
+/// of whitespace to a single space.
+pub fn eq_normalized(a: &str, b: &str, ignore_case: bool, collapse: bool) -> bool {
+    if ignore_case {
+        chars(a, collapse)
+            .flat_map(fold)
+            .eq(chars(b, collapse).flat_map(fold))
+    } else {
+        chars(a, collapse).eq(chars(b, collapse))
+    }
+}
+
+/// Like [`eq_normalized`], but compares the NFC forms of the strings.
+#[cfg(feature = "unicode-normalization")]
+pub fn eq_nfc(a: &str, b: &str, ignore_case: bool, collapse: bool) -> bool {
+    use unicode_normalization::UnicodeNormalization;
+
+    if ignore_case {
+        chars(a, collapse)
+            .nfc()
+            .flat_map(fold)
+            .eq(chars(b, collapse).nfc().flat_map(fold))
+    } else {
+        chars(a, collapse).nfc().eq(chars(b, collapse).nfc())
+    }
+}
+
+/// The full Unicode case folding of `c`, so that "STRASSE" matches "straße" and "ΣΑΣ" matches
+/// "σας".
+fn fold(c: char) -> impl Iterator<Item = char> {
+    // Lowercasing the uppercase of the lowercase folds every character like `CaseFolding.txt`,
+    // except the dotless i, whose uppercase is a plain `I` but which folds to itself.
+    let dotless = c == '\u{131}';
+    let folded = c
+        .to_lowercase()
+        .flat_map(char::to_uppercase)
+        .flat_map(char::to_lowercase);
+    (!dotless)
+        .then_some(folded)
+        .into_iter()
+        .flatten()
+        .chain(dotless.then_some(c))
+}
+
+fn chars(s: &str, collapse: bool) -> impl Iterator<Item = char> + '_ {
+    let mut in_whitespace = false;
+    s.chars().filter_map(move |c| {
+        if !collapse || !c.is_whitespace() {
+            in_whitespace = false;
+            Some(c)
+        } else if in_whitespace {
+            None
+        } else {
+            in_whitespace = true;
+            Some(' ')
+        }
+    })
+}
+
+/// Iterates over the matches of `longest` in `haystack`, moving past each match and one char
+/// at a time between matches.
+pub fn find_all<'a, T: 'a>(
+    haystack: &'a str,
+    longest: fn(&str) -> Option<(T, usize)>,
+) -> impl Iterator<Item = (usize, T)> + 'a {
+    let mut pos = 0;
+    core::iter::from_fn(move || {
+        while let Some(c) = haystack[pos..].chars().next() {
+            let start = pos;
+            match longest(&haystack[start..]) {
+                Some((variant, len)) if len > 0 => {
+                    pos += len;
+                    return Some((start, variant));
+                }
+                _ => pos += c.len_utf8(),
+            }
+        }
+        None
+    })
+}
+
+/// The types a value or alias can have.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be the value of a variant",
+    label = "expected a `&'static str`",
+    note = "an enum with `char` values needs the `#[char]` attribute"
+)]
+pub trait Value: Copy {}
+
+impl Value for &'static str {}
+
+/// Checks the type of a value or alias.
+pub const fn check_value<T: Value>(_: T) {}
+
+/// Counts how often `value` occurs in `values`, at compile time.
+pub const fn count_str(values: &[&str], value: &str) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i < values.len() {
+        if eq_bytes(values[i].as_bytes(), value.as_bytes()) {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+/// Whether `value` is one of `values`, at compile time.
+pub const fn contains_str(values: &[&str], value: &str) -> bool {
+    let mut i = 0;
+    while i < values.len() {
+        if eq_bytes(values[i].as_bytes(), value.as_bytes()) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Length of the longest string in `values`, or 0 if there are none.
+pub const fn max_len(values: &[&str]) -> usize {
+    let mut max = 0;
+    let mut i = 0;
+    while i < values.len() {
+        if values[i].len() > max {
+            max = values[i].len();
+        }
+        i += 1;
+    }
+    max
+}
+
+/// Length of the shortest string in `values`, or 0 if there are none.
+pub const fn min_len(values: &[&str]) -> usize {
+    if values.is_empty() {
+        return 0;
+    }
+    let mut min = usize::MAX;
+    let mut i = 0;
+    while i < values.len() {
+        if values[i].len() < min {
+            min = values[i].len();
+        }
+        i += 1;
+    }
+    min
+}
+
+/// Encodes `c` as UTF-8, `N` being its length.
+pub const fn encode_char<const N: usize>(c: char) -> [u8; N] {
+    let code = c as u32;
+    let mut bytes = [0; N];
+    match N {
+        1 => bytes[0] = code as u8,
+        2 => {
+            bytes[0] = 0xC0 | (code >> 6) as u8;
+            bytes[1] = 0x80 | (code & 0x3F) as u8;
+        }
+        3 => {
+            bytes[0] = 0xE0 | (code >> 12) as u8;
+            bytes[1] = 0x80 | ((code >> 6) & 0x3F) as u8;
+            bytes[2] = 0x80 | (code & 0x3F) as u8;
+        }
+        _ => {
+            bytes[0] = 0xF0 | (code >> 18) as u8;
+            bytes[1] = 0x80 | ((code >> 12) & 0x3F) as u8;
+            bytes[2] = 0x80 | ((code >> 6) & 0x3F) as u8;
+            bytes[3] = 0x80 | (code & 0x3F) as u8;
+        }
+    }
+    bytes
+}
+
+/// Lists the strings of `entries` with their positions, sorted by length and otherwise in the
+/// order of `entries`, for [`find_by_len`].
+pub const fn len_table<T, const N: usize>(
+    entries: &[(&'static str, T)],
+) -> [(&'static str, usize); N] {
+    let mut table = [("", 0); N];
+    let mut i = 0;
+    while i < N {
+        let mut j = i;
+        while j > 0 && entries[i].0.len() < table[j - 1].0.len() {
+            table[j] = table[j - 1];
+            j -= 1;
+        }
+        table[j] = (entries[i].0, i);
+        i += 1;
+    }
+    table
+}
+
+/// Finds the position of `value` in a table built by [`len_table`], comparing only the strings
+/// of the same length and first byte in full.
+pub fn find_by_len(table: &[(&str, usize)], value: &str) -> Option<usize> {
+    let start = table.partition_point(|(s, _)| s.len() < value.len());
+    let first = value.as_bytes().first();
+    table[start..]
+        .iter()
+        .take_while(|(s, _)| s.len() == value.len())
+        .find(|(s, _)| s.as_bytes().first() == first && *s == value)
+        .map(|&(_, i)| i)
+}
+
+/// Copies `entries` into an array sorted by their strings, for binary search with
+/// `str::cmp`.
+pub const fn sorted_table<const N: usize>(
+    entries: &[(&'static str, usize)],
+) -> [(&'static str, usize); N] {
+    let mut table = [("", 0); N];
+    let mut i = 0;
+    while i < N {
+        // Insertion sort, moving each entry in front of the larger ones before it.
+        let mut j = i;
+        while j > 0 && is_less(entries[i].0.as_bytes(), table[j - 1].0.as_bytes()) {
+            table[j] = table[j - 1];
+            j -= 1;
+        }
+        table[j] = entries[i];
+        i += 1;
+    }
+    table
+}
+
+const fn is_less(a: &[u8], b: &[u8]) -> bool {
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+        i += 1;
+    }
+    a.len() < b.len()
+}
+
+/// Length in bytes of the alternation built by [`pattern`].
+pub const fn pattern_len(values: &[&str]) -> usize {
+    let mut len = values.len().saturating_sub(1);
+    let mut i = 0;
+    while i < values.len() {
+        let bytes = values[i].as_bytes();
+        let mut j = 0;
+        while j < bytes.len() {
+            len += if is_regex_meta(bytes[j]) { 2 } else { 1 };
+            j += 1;
+        }
+        i += 1;
+    }
+    len
+}
+
+/// Joins `values` with `|` into a regex alternation, escaping the metacharacters. Longer
+/// values come first, so a regex engine that picks the first matching alternative finds the
+/// longest one.
+pub const fn pattern<const N: usize>(values: &[&str]) -> [u8; N] {
+    let mut out = [0; N];
+    let mut pos = 0;
+    let mut len = max_len(values) + 1;
+    while len > 0 {
+        len -= 1;
+        let mut i = 0;
+        while i < values.len() {
+            let bytes = values[i].as_bytes();
+            if bytes.len() == len {
+                if pos > 0 {
+                    out[pos] = b'|';
+                    pos += 1;
+                }
+                let mut j = 0;
+                while j < bytes.len() {
+                    if is_regex_meta(bytes[j]) {
+                        out[pos] = b'\\';
+                        pos += 1;
+                    }
+                    out[pos] = bytes[j];
+                    pos += 1;
+                    j += 1;
+                }
+            }
+            i += 1;
+        }
+    }
+    out
+}
+
+/// The characters `regex::escape` escapes.
+const fn is_regex_meta(b: u8) -> bool {
+    matches!(
+        b,
+        b'\\'
+            | b'.'
+            | b'+'
+            | b'*'
+            | b'?'
+            | b'('
+            | b')'
+            | b'|'
+            | b'['
+            | b']'
+            | b'{'
+            | b'}'
+            | b'^'
+            | b'$'
+            | b'#'
+            | b'&'
+            | b'-'
+            | b'~'
+    )
+}
+
+const fn eq_bytes(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+#[cfg(feature = "arbitrary")]
+pub use arbitrary;
+
+#[cfg(feature = "serde")]
+pub use crate::impl_serde::de::deserialize_from_str;
+#[cfg(feature = "schemars")]
+pub use schemars;
+
+#[cfg(feature = "serde")]
+pub use serde;
+
+#[cfg(feature = "clap")]
+pub use clap;
+
+#[cfg(feature = "nom")]
+pub use nom;
+
+#[cfg(feature = "phf")]
+pub use phf;
+
+#[cfg(feature = "diesel")]
+pub use diesel;
+
+#[cfg(feature = "postgres")]
+pub use bytes;
+#[cfg(feature = "postgres")]
+pub use postgres_types;
+
+#[cfg(feature = "proptest")]
+pub use proptest;
+
+#[cfg(feature = "quickcheck")]
+pub use quickcheck;
+
+#[cfg(feature = "rand")]
+pub use rand;
+
+#[cfg(feature = "pyo3")]
+pub use pyo3;
+
+#[cfg(feature = "rusqlite")]
+pub use rusqlite;
+
+#[cfg(feature = "sqlx")]
+pub use sqlx;
+
+#[cfg(feature = "strum")]
+pub use strum;
+
+#[cfg(feature = "wasm")]
+pub use wasm_bindgen;
+
+#[cfg(feature = "winnow")]
+pub use winnow;
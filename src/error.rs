@@ -0,0 +1,427 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Error returned by the generated `FromStr` implementation when a string does not match
+/// any variant of the enum `T`.
+///
+/// The rejected input is only kept when the `alloc` feature is enabled. `Display` lists the
+/// values that would have been accepted, or the closest one when the enum is `#[suggest]`.
+pub struct ParseError<T> {
+    name: &'static str,
+    values: &'static [&'static str],
+    suggestion: Option<&'static str>,
+    #[cfg(feature = "alloc")]
+    input: Box<str>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> ParseError<T> {
+    /// Creates an error for the enum called `name` that failed to parse `input`.
+    #[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
+    pub fn new(name: &'static str, input: &str) -> Self {
+        ParseError {
+            name,
+            values: &[],
+            suggestion: None,
+            #[cfg(feature = "alloc")]
+            input: input.into(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates an error for the enum called `name` that failed to parse the bytes `input`,
+    /// which are kept with invalid UTF-8 replaced.
+    #[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
+    pub fn from_bytes(name: &'static str, input: &[u8]) -> Self {
+        ParseError {
+            name,
+            values: &[],
+            suggestion: None,
+            #[cfg(feature = "alloc")]
+            input: String::from_utf8_lossy(input).into(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Sets the values the input was expected to match, which `Display` lists.
+    pub fn with_values(mut self, values: &'static [&'static str]) -> Self {
+        self.values = values;
+        self
+    }
+
+    /// Suggests the value closest to `input`, if one is close enough to be a likely typo.
+    ///
+    /// The values come from [`with_values`](Self::with_values). Without the `alloc` feature
+    /// no suggestion is made.
+    #[cfg_attr(not(feature = "alloc"), allow(unused_mut, unused_variables))]
+    pub fn with_suggestion(mut self, input: &str) -> Self {
+        #[cfg(feature = "alloc")]
+        {
+            let max = input.chars().count() / 3 + 1;
+            self.suggestion = self
+                .values
+                .iter()
+                .map(|value| (edit_distance(input, value), *value))
+                .filter(|&(distance, _)| distance <= max)
+                .min_by_key(|&(distance, _)| distance)
+                .map(|(_, value)| value);
+        }
+        self
+    }
+
+    /// Name of the enum that failed to parse.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The values the input was expected to match.
+    pub const fn values(&self) -> &'static [&'static str] {
+        self.values
+    }
+
+    /// The value suggested by [`with_suggestion`](Self::with_suggestion).
+    pub const fn suggestion(&self) -> Option<&'static str> {
+        self.suggestion
+    }
+
+    /// The string that did not match any variant.
+    #[cfg(feature = "alloc")]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl<T> fmt::Debug for ParseError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("ParseError");
+        debug.field("name", &self.name);
+        debug.field("values", &self.values);
+        debug.field("suggestion", &self.suggestion);
+        #[cfg(feature = "alloc")]
+        debug.field("input", &self.input);
+        debug.finish()
+    }
+}
+
+impl<T> fmt::Display for ParseError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown {}", self.name)?;
+        #[cfg(feature = "alloc")]
+        write!(f, ": {:?}", self.input)?;
+
+        if let Some(suggestion) = self.suggestion {
+            write!(f, ", did you mean {suggestion:?}?")?;
+        } else if let Some((first, rest)) = self.values.split_first() {
+            write!(f, ", expected one of {first:?}")?;
+            for value in rest {
+                write!(f, ", {value:?}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> core::error::Error for ParseError<T> {}
+
+impl<T> Clone for ParseError<T> {
+    fn clone(&self) -> Self {
+        ParseError {
+            name: self.name,
+            values: self.values,
+            suggestion: self.suggestion,
+            #[cfg(feature = "alloc")]
+            input: self.input.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> PartialEq for ParseError<T> {
+    #[cfg(feature = "alloc")]
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.input == other.input
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl<T> Eq for ParseError<T> {}
+
+/// Error returned by the generated `TryFrom` implementations for integers when there is no
+/// variant of the enum `T` at the index.
+pub struct IndexError<T> {
+    name: &'static str,
+    index: u64,
+    count: usize,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> IndexError<T> {
+    /// Creates an error for the enum called `name`, which has `count` variants, that has no
+    /// variant at `index`.
+    pub const fn new(name: &'static str, index: u64, count: usize) -> Self {
+        IndexError {
+            name,
+            index,
+            count,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Name of the enum that has no variant at the index.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The index that did not match any variant.
+    pub const fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// The number of variants of the enum.
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl<T> fmt::Debug for IndexError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IndexError")
+            .field("name", &self.name)
+            .field("index", &self.index)
+            .field("count", &self.count)
+            .finish()
+    }
+}
+
+impl<T> fmt::Display for IndexError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "no {} at index {}, expected less than {}",
+            self.name, self.index, self.count
+        )
+    }
+}
+
+impl<T> core::error::Error for IndexError<T> {}
+
+impl<T> Clone for IndexError<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for IndexError<T> {}
+
+impl<T> PartialEq for IndexError<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.index == other.index
+    }
+}
+
+impl<T> Eq for IndexError<T> {}
+
+/// Error returned by the generated `parse_list` when an element of the list does not match
+/// any variant of the enum `T`.
+#[cfg(feature = "alloc")]
+pub struct ListError<T> {
+    index: usize,
+    offset: usize,
+    error: ParseError<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> ListError<T> {
+    /// Creates an error for the element at `index`, which starts `offset` bytes into the
+    /// input, that failed to parse with `error`.
+    pub fn new(index: usize, offset: usize, error: ParseError<T>) -> Self {
+        ListError {
+            index,
+            offset,
+            error,
+        }
+    }
+
+    /// Position of the element among the elements of the list, starting at 0.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Position in bytes of the element in the input.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The error from parsing the element.
+    pub const fn parse_error(&self) -> &ParseError<T> {
+        &self.error
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> fmt::Debug for ListError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ListError")
+            .field("index", &self.index)
+            .field("offset", &self.offset)
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> fmt::Display for ListError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid element {} at byte {}: {}",
+            self.index, self.offset, self.error
+        )
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: 'static> core::error::Error for ListError<T> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Clone for ListError<T> {
+    fn clone(&self) -> Self {
+        ListError {
+            index: self.index,
+            offset: self.offset,
+            error: self.error.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> PartialEq for ListError<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index && self.offset == other.offset && self.error == other.error
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Eq for ListError<T> {}
+
+/// Error returned by the generated `from_env` when an environment variable is not set or
+/// holds no value of the enum `T`.
+#[cfg(feature = "std")]
+pub struct EnvError<T> {
+    var: Box<str>,
+    error: Option<ParseError<T>>,
+}
+
+#[cfg(feature = "std")]
+impl<T> EnvError<T> {
+    /// Creates an error for the environment variable `var`, which is not set.
+    pub fn not_present(var: &str) -> Self {
+        EnvError {
+            var: var.into(),
+            error: None,
+        }
+    }
+
+    /// Creates an error for the environment variable `var`, whose value failed to parse.
+    pub fn invalid(var: &str, error: ParseError<T>) -> Self {
+        EnvError {
+            var: var.into(),
+            error: Some(error),
+        }
+    }
+
+    /// Name of the environment variable.
+    pub fn var(&self) -> &str {
+        &self.var
+    }
+
+    /// Whether the variable is not set, as opposed to holding an invalid value.
+    pub fn is_not_present(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// The error from parsing the value of the variable, if it is set.
+    pub fn parse_error(&self) -> Option<&ParseError<T>> {
+        self.error.as_ref()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> fmt::Debug for EnvError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EnvError")
+            .field("var", &self.var)
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> fmt::Display for EnvError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.error {
+            None => write!(f, "environment variable {} is not set", self.var),
+            Some(error) => write!(f, "invalid environment variable {}: {}", self.var, error),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: 'static> core::error::Error for EnvError<T> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        self.error
+            .as_ref()
+            .map(|error| error as &(dyn core::error::Error + 'static))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Clone for EnvError<T> {
+    fn clone(&self) -> Self {
+        EnvError {
+            var: self.var.clone(),
+            error: self.error.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> PartialEq for EnvError<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.var == other.var && self.error == other.error
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Eq for EnvError<T> {}
+
+/// Levenshtein distance between `a` and `b`, counted in chars and ignoring ASCII case.
+#[cfg(feature = "alloc")]
+fn edit_distance(a: &str, b: &str) -> usize {
+    let mut row: Vec<usize> = (0..=b.chars().count()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.chars().enumerate() {
+            let substitution = diagonal + usize::from(!ca.eq_ignore_ascii_case(&cb));
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[row.len() - 1]
+}
@@ -0,0 +1,45 @@
+//! The `enum_str_error!` macro, which declares an enum of error kinds with `enum_str!`.
+
+/// Like [`enum_str!`](crate::enum_str), but the enum is an error type: the value of each
+/// variant is its message, and the enum implements [`core::error::Error`] on top of the usual
+/// surface.
+///
+/// The input is the same as for `enum_str!`, so an integer after the message gives each
+/// variant a code, returned by `code`. The error type relies on the `Debug` derive and on the
+/// generated `Display`, which prints the message.
+/// ```
+/// use enum_str::enum_str_error;
+///
+/// enum_str_error! {
+///     #[derive(Debug, Clone, Copy, PartialEq)]
+///     pub FetchError,
+///     (NotFound, "resource not found", 404),
+///     (Timeout, "request timed out", 504),
+/// }
+///
+/// fn fetch(path: &str) -> Result<&'static str, FetchError> {
+///     match path {
+///         "/" => Ok("index"),
+///         _ => Err(FetchError::NotFound),
+///     }
+/// }
+///
+/// let err: Box<dyn std::error::Error> = fetch("/missing").unwrap_err().into();
+/// assert_eq!("resource not found", err.to_string());
+/// assert_eq!(504, FetchError::Timeout.code());
+/// ```
+#[macro_export]
+macro_rules! enum_str_error {
+    ($(#[$mattr:meta])* $mvis:vis mod $module:ident, $($rest:tt)*) => {
+        $(#[$mattr])*
+        $mvis mod $module {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $crate::enum_str_error! { $($rest)* }
+        }
+    };
+    ($($input:tt)*) => {
+        $crate::enum_str! { #[__enum_str_error_enum] $($input)* }
+    };
+}
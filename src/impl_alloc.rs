@@ -0,0 +1,107 @@
+//! Impls for generated enums that need an allocator, enabled by the `alloc` feature.
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_alloc {
+    ($name:ident, [], $(#[cfg $cfg:tt] ($key:ident, $value:expr)),*) => {
+        $crate::__enum_str_alloc!(@string $name);
+
+        impl ::core::convert::From<$name> for $crate::__private::Cow<'static, str> {
+            fn from(val: $name) -> Self {
+                $crate::__private::Cow::Borrowed(val.__enum_str_as_str())
+            }
+        }
+    };
+    ($name:ident, [$other:ident], $(#[cfg $cfg:tt] ($key:ident, $value:expr)),*) => {
+        $crate::__enum_str_alloc!(@string $name);
+
+        impl ::core::convert::From<$name> for $crate::__private::Cow<'static, str> {
+            fn from(val: $name) -> Self {
+                match val {
+                    $(
+                        #[cfg $cfg]
+                        $name::$key => $crate::__private::Cow::Borrowed($value),
+                    )*
+                    $name::$other(value) => $crate::__private::Cow::Owned(value),
+                }
+            }
+        }
+    };
+
+    (@list [$($opt:tt)*] $vis:vis $name:ident) => {
+        #[allow(dead_code)]
+        impl $name {
+            $crate::__enum_str_item! { parse_list, [$($opt)*], $vis,
+                { /// Parses the elements of `input` separated by `sep`, each with surrounding
+                  /// whitespace trimmed. An input of only whitespace is the empty list.
+                } { fn } {
+                    (input: &str, sep: &str) -> ::core::result::Result<$crate::__private::Vec<Self>, $crate::ListError<Self>> {
+                        let mut list = $crate::__private::Vec::new();
+                        if input.trim().is_empty() {
+                            return ::core::result::Result::Ok(list);
+                        }
+                        let mut start = 0;
+                        for (index, element) in input.split(sep).enumerate() {
+                            let offset = start + element.len() - element.trim_start().len();
+                            match $name::__enum_str_from_str(element.trim()) {
+                                ::core::result::Result::Ok(variant) => list.push(variant),
+                                ::core::result::Result::Err(err) => {
+                                    return ::core::result::Result::Err($crate::ListError::new(index, offset, err));
+                                }
+                            }
+                            start += element.len() + sep.len();
+                        }
+                        ::core::result::Result::Ok(list)
+                    }
+                }
+            }
+
+            $crate::__enum_str_item! { join, [$($opt)*], $vis,
+                { /// Joins the values of `list` with `sep`, the inverse of `parse_list`.
+                } { fn } {
+                    (list: &[Self], sep: &str) -> $crate::__private::String {
+                        let mut joined = $crate::__private::String::new();
+                        for (i, variant) in list.iter().enumerate() {
+                            if i > 0 {
+                                joined.push_str(sep);
+                            }
+                            joined.push_str(variant.__enum_str_as_str());
+                        }
+                        joined
+                    }
+                }
+            }
+        }
+    };
+
+    (@try_from $name:ident) => {
+        impl ::core::convert::TryFrom<$crate::__private::String> for $name {
+            type Error = $crate::__private::String;
+
+            /// Parses `val` like `from_str`, giving it back as the error when it matches no
+            /// variant.
+            fn try_from(val: $crate::__private::String) -> ::core::result::Result<Self, $crate::__private::String> {
+                match $name::__enum_str_from_str(&val) {
+                    ::core::result::Result::Ok(variant) => ::core::result::Result::Ok(variant),
+                    ::core::result::Result::Err(_) => ::core::result::Result::Err(val),
+                }
+            }
+        }
+    };
+
+    (@string $name:ident) => {
+        impl ::core::convert::From<$name> for $crate::__private::String {
+            fn from(val: $name) -> Self {
+                $crate::__private::String::from(val.__enum_str_as_str())
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_alloc {
+    ($($tt:tt)*) => {};
+}
@@ -0,0 +1,29 @@
+//! `arbitrary::Arbitrary` impl for generated enums, enabled by the `arbitrary` feature.
+
+#[cfg(feature = "arbitrary")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_arbitrary {
+    ($name:ident, $(#[cfg $cfg:tt] $key:ident),*) => {
+        impl<'a> $crate::__private::arbitrary::Arbitrary<'a> for $name {
+            fn arbitrary(
+                u: &mut $crate::__private::arbitrary::Unstructured<'a>,
+            ) -> $crate::__private::arbitrary::Result<Self> {
+                let index = u.choose_index($name::COUNT)?;
+                // Moving out of the array keeps the impl free of a `Clone` bound.
+                ::core::result::Result::Ok([$(#[cfg $cfg] $name::$key),*].into_iter().nth(index).unwrap())
+            }
+
+            fn size_hint(depth: usize) -> (usize, ::core::option::Option<usize>) {
+                <u32 as $crate::__private::arbitrary::Arbitrary<'a>>::size_hint(depth)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "arbitrary"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_arbitrary {
+    ($($tt:tt)*) => {};
+}
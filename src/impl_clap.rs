@@ -0,0 +1,37 @@
+//! `clap::ValueEnum` impl for generated enums, enabled by the `clap` feature.
+
+#[cfg(feature = "clap")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_clap {
+    ($name:ident, [], $(#[cfg $cfg:tt] ($key:ident, $value:expr $(, [$($alias:expr),*])*)),*) => {
+        impl $crate::__private::clap::ValueEnum for $name {
+            fn value_variants<'a>() -> &'a [Self] {
+                $name::VARIANTS
+            }
+
+            fn to_possible_value(
+                &self,
+            ) -> ::core::option::Option<$crate::__private::clap::builder::PossibleValue> {
+                let value = match self {
+                    $(
+                        #[cfg $cfg]
+                        &$name::$key => $crate::__private::clap::builder::PossibleValue::new($value)
+                            $($(.alias($alias))*)*,
+                    )*
+                };
+                ::core::option::Option::Some(value)
+            }
+        }
+    };
+    // `ValueEnum` can only list fixed values, which would reject what the catch-all accepts.
+    // Without it, `value_parser!` falls back to `FromStr`.
+    ($name:ident, [$other:ident], $($variant:tt)*) => {};
+}
+
+#[cfg(not(feature = "clap"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_clap {
+    ($($tt:tt)*) => {};
+}
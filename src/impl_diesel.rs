@@ -0,0 +1,108 @@
+//! `diesel` impls for generated enums, enabled by the `diesel` feature. The enum maps to the
+//! `Text` SQL type.
+
+#[cfg(feature = "diesel")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_diesel {
+    ($name:ident) => {
+        $crate::__enum_str_diesel! { @expression $name, $crate::__private::diesel::sql_types::Text }
+        $crate::__enum_str_diesel! {
+            @expression $name,
+            $crate::__private::diesel::sql_types::Nullable<$crate::__private::diesel::sql_types::Text>
+        }
+
+        impl<DB> $crate::__private::diesel::serialize::ToSql<$crate::__private::diesel::sql_types::Text, DB> for $name
+        where
+            DB: $crate::__private::diesel::backend::Backend,
+            str: $crate::__private::diesel::serialize::ToSql<$crate::__private::diesel::sql_types::Text, DB>,
+        {
+            fn to_sql<'b>(
+                &'b self,
+                out: &mut $crate::__private::diesel::serialize::Output<'b, '_, DB>,
+            ) -> $crate::__private::diesel::serialize::Result {
+                <str as $crate::__private::diesel::serialize::ToSql<$crate::__private::diesel::sql_types::Text, DB>>::to_sql(
+                    self.__enum_str_as_str(),
+                    out,
+                )
+            }
+        }
+
+        impl<DB>
+            $crate::__private::diesel::serialize::ToSql<
+                $crate::__private::diesel::sql_types::Nullable<$crate::__private::diesel::sql_types::Text>,
+                DB,
+            > for $name
+        where
+            DB: $crate::__private::diesel::backend::Backend,
+            Self: $crate::__private::diesel::serialize::ToSql<$crate::__private::diesel::sql_types::Text, DB>,
+        {
+            fn to_sql<'b>(
+                &'b self,
+                out: &mut $crate::__private::diesel::serialize::Output<'b, '_, DB>,
+            ) -> $crate::__private::diesel::serialize::Result {
+                $crate::__private::diesel::serialize::ToSql::<$crate::__private::diesel::sql_types::Text, DB>::to_sql(self, out)
+            }
+        }
+
+        impl<DB> $crate::__private::diesel::deserialize::FromSql<$crate::__private::diesel::sql_types::Text, DB> for $name
+        where
+            DB: $crate::__private::diesel::backend::Backend,
+            $crate::__private::String: $crate::__private::diesel::deserialize::FromSql<$crate::__private::diesel::sql_types::Text, DB>,
+        {
+            fn from_sql(
+                bytes: <DB as $crate::__private::diesel::backend::Backend>::RawValue<'_>,
+            ) -> $crate::__private::diesel::deserialize::Result<Self> {
+                let value = <$crate::__private::String as $crate::__private::diesel::deserialize::FromSql<
+                    $crate::__private::diesel::sql_types::Text,
+                    DB,
+                >>::from_sql(bytes)?;
+                ::core::result::Result::Ok($name::__enum_str_from_str(&value)?)
+            }
+        }
+
+        impl<DB, ST> $crate::__private::diesel::deserialize::Queryable<ST, DB> for $name
+        where
+            DB: $crate::__private::diesel::backend::Backend,
+            ST: $crate::__private::diesel::sql_types::SingleValue,
+            Self: $crate::__private::diesel::deserialize::FromSql<ST, DB>,
+        {
+            type Row = Self;
+
+            fn build(row: Self) -> $crate::__private::diesel::deserialize::Result<Self> {
+                ::core::result::Result::Ok(row)
+            }
+        }
+    };
+
+    // The expressions are the ones diesel builds for the string, which keeps the generated code
+    // off diesel's internal `Bound` type. Binding the enum by value copies its string.
+    (@expression $name:ident, $sql_type:ty) => {
+        impl $crate::__private::diesel::expression::AsExpression<$sql_type> for $name {
+            type Expression = <$crate::__private::String as $crate::__private::diesel::expression::AsExpression<$sql_type>>::Expression;
+
+            fn as_expression(self) -> Self::Expression {
+                <$crate::__private::String as $crate::__private::diesel::expression::AsExpression<$sql_type>>::as_expression(
+                    $crate::__private::String::from(self.__enum_str_as_str()),
+                )
+            }
+        }
+
+        impl<'expr> $crate::__private::diesel::expression::AsExpression<$sql_type> for &'expr $name {
+            type Expression = <&'expr str as $crate::__private::diesel::expression::AsExpression<$sql_type>>::Expression;
+
+            fn as_expression(self) -> Self::Expression {
+                <&'expr str as $crate::__private::diesel::expression::AsExpression<$sql_type>>::as_expression(
+                    self.__enum_str_as_str(),
+                )
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "diesel"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_diesel {
+    ($($tt:tt)*) => {};
+}
@@ -0,0 +1,33 @@
+//! A `nom` parser for generated enums, enabled by the `nom` feature.
+
+#[cfg(feature = "nom")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_nom {
+    ([$($opt:tt)*] $vis:vis $name:ident) => {
+        #[allow(dead_code)]
+        impl $name {
+            $crate::__enum_str_item! { parse, [$($opt)*], $vis,
+                { /// Parses the longest value or alias at the start of `input`, for use in `nom`
+                  /// grammars.
+                } { fn } {
+                    (input: &str) -> $crate::__private::nom::IResult<&str, Self> {
+                        match $name::match_longest_at(input) {
+                            ::core::option::Option::Some((variant, len)) => ::core::result::Result::Ok((&input[len..], variant)),
+                            ::core::option::Option::None => ::core::result::Result::Err($crate::__private::nom::Err::Error(
+                                $crate::__private::nom::error::Error::new(input, $crate::__private::nom::error::ErrorKind::Tag),
+                            )),
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "nom"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_nom {
+    ($($tt:tt)*) => {};
+}
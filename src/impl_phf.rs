@@ -0,0 +1,40 @@
+//! Perfect hash lookup for `from_str`, used by enums marked `#[phf]` when the `phf` feature is
+//! enabled.
+
+#[cfg(feature = "phf")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_phf {
+    ($name:ident, $val:expr, $(#[cfg $cfg:tt] ($key:ident, $value:expr $(, [$($alias:expr),*])*)),*) => {{
+        // The map built by `phf_map!` refers to `phf::Map`.
+        use $crate::__private::phf;
+
+        // `phf_map!` has no `cfg` on entries, so the values of a disabled variant map to `None`.
+        static MAP: phf::Map<&'static str, fn() -> ::core::option::Option<$name>> = phf::phf_map! {
+            $(
+                $value => $crate::__enum_str_phf!(@variant $cfg $name::$key),
+                $($($alias => $crate::__enum_str_phf!(@variant $cfg $name::$key),)*)*
+            )*
+        };
+
+        MAP.get($val).and_then(|variant| variant())
+    }};
+    (@variant $cfg:tt $name:ident::$key:ident) => {
+        || {
+            #[allow(unused_variables)]
+            let variant: ::core::option::Option<$name> = ::core::option::Option::None;
+            #[cfg $cfg]
+            let variant = ::core::option::Option::Some($name::$key);
+            variant
+        }
+    };
+}
+
+#[cfg(not(feature = "phf"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_phf {
+    ($($tt:tt)*) => {
+        ::core::compile_error!("`#[phf]` requires the `phf` feature of enum_str")
+    };
+}
@@ -0,0 +1,77 @@
+//! `postgres-types` impls for generated enums, enabled by the `postgres` feature. The enum is
+//! stored in the same column types as `&str`.
+
+#[cfg(feature = "postgres")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_postgres {
+    ($name:ident) => {
+        impl $crate::__private::postgres_types::ToSql for $name {
+            fn to_sql(
+                &self,
+                ty: &$crate::__private::postgres_types::Type,
+                out: &mut $crate::__private::bytes::BytesMut,
+            ) -> ::core::result::Result<
+                $crate::__private::postgres_types::IsNull,
+                $crate::__private::Box<
+                    dyn ::core::error::Error + ::core::marker::Sync + ::core::marker::Send,
+                >,
+            > {
+                <&str as $crate::__private::postgres_types::ToSql>::to_sql(
+                    &self.__enum_str_as_str(),
+                    ty,
+                    out,
+                )
+            }
+
+            fn accepts(ty: &$crate::__private::postgres_types::Type) -> bool {
+                <&str as $crate::__private::postgres_types::ToSql>::accepts(ty)
+            }
+
+            fn to_sql_checked(
+                &self,
+                ty: &$crate::__private::postgres_types::Type,
+                out: &mut $crate::__private::bytes::BytesMut,
+            ) -> ::core::result::Result<
+                $crate::__private::postgres_types::IsNull,
+                $crate::__private::Box<
+                    dyn ::core::error::Error + ::core::marker::Sync + ::core::marker::Send,
+                >,
+            > {
+                // Same check as `to_sql_checked!` would generate, since `accepts` matches `&str`.
+                <&str as $crate::__private::postgres_types::ToSql>::to_sql_checked(
+                    &self.__enum_str_as_str(),
+                    ty,
+                    out,
+                )
+            }
+        }
+
+        impl<'a> $crate::__private::postgres_types::FromSql<'a> for $name {
+            fn from_sql(
+                ty: &$crate::__private::postgres_types::Type,
+                raw: &'a [u8],
+            ) -> ::core::result::Result<
+                Self,
+                $crate::__private::Box<
+                    dyn ::core::error::Error + ::core::marker::Sync + ::core::marker::Send,
+                >,
+            > {
+                let value =
+                    <&str as $crate::__private::postgres_types::FromSql>::from_sql(ty, raw)?;
+                ::core::result::Result::Ok($name::__enum_str_from_str(value)?)
+            }
+
+            fn accepts(ty: &$crate::__private::postgres_types::Type) -> bool {
+                <&str as $crate::__private::postgres_types::FromSql>::accepts(ty)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "postgres"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_postgres {
+    ($($tt:tt)*) => {};
+}
@@ -0,0 +1,25 @@
+//! `proptest::arbitrary::Arbitrary` impl for generated enums, enabled by the `proptest` feature.
+
+#[cfg(feature = "proptest")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_proptest {
+    ($name:ident, $(#[cfg $cfg:tt] $key:ident),*) => {
+        impl $crate::__private::proptest::arbitrary::Arbitrary for $name {
+            type Parameters = ();
+            type Strategy = $crate::__private::proptest::strategy::Map<::core::ops::Range<usize>, fn(usize) -> Self>;
+
+            fn arbitrary_with(_: ()) -> Self::Strategy {
+                let variant: fn(usize) -> Self = |index| [$(#[cfg $cfg] $name::$key),*].into_iter().nth(index).unwrap();
+                $crate::__private::proptest::strategy::Strategy::prop_map(0..$name::COUNT, variant)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "proptest"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_proptest {
+    ($($tt:tt)*) => {};
+}
@@ -0,0 +1,71 @@
+//! `pyo3` conversions for generated enums, enabled by the `pyo3` feature. The enum is passed
+//! to Python as its string value.
+
+#[cfg(feature = "pyo3")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_pyo3 {
+    ($name:ident) => {
+        impl<'py> $crate::__private::pyo3::IntoPyObject<'py> for $name {
+            type Target = $crate::__private::pyo3::types::PyString;
+            type Output =
+                $crate::__private::pyo3::Bound<'py, $crate::__private::pyo3::types::PyString>;
+            type Error = ::core::convert::Infallible;
+
+            fn into_pyobject(
+                self,
+                py: $crate::__private::pyo3::Python<'py>,
+            ) -> ::core::result::Result<
+                $crate::__private::pyo3::Bound<'py, $crate::__private::pyo3::types::PyString>,
+                ::core::convert::Infallible,
+            > {
+                ::core::result::Result::Ok($crate::__private::pyo3::types::PyString::new(
+                    py,
+                    self.__enum_str_as_str(),
+                ))
+            }
+        }
+
+        impl<'py> $crate::__private::pyo3::IntoPyObject<'py> for &$name {
+            type Target = $crate::__private::pyo3::types::PyString;
+            type Output =
+                $crate::__private::pyo3::Bound<'py, $crate::__private::pyo3::types::PyString>;
+            type Error = ::core::convert::Infallible;
+
+            fn into_pyobject(
+                self,
+                py: $crate::__private::pyo3::Python<'py>,
+            ) -> ::core::result::Result<
+                $crate::__private::pyo3::Bound<'py, $crate::__private::pyo3::types::PyString>,
+                ::core::convert::Infallible,
+            > {
+                ::core::result::Result::Ok($crate::__private::pyo3::types::PyString::new(
+                    py,
+                    self.__enum_str_as_str(),
+                ))
+            }
+        }
+
+        impl<'a, 'py> $crate::__private::pyo3::FromPyObject<'a, 'py> for $name {
+            type Error = $crate::__private::pyo3::PyErr;
+
+            fn extract(
+                obj: $crate::__private::pyo3::Borrowed<'a, 'py, $crate::__private::pyo3::PyAny>,
+            ) -> $crate::__private::pyo3::PyResult<Self> {
+                let value: $crate::__private::pyo3::pybacked::PyBackedStr = obj.extract()?;
+                $name::__enum_str_from_str(&value).map_err(|err| {
+                    $crate::__private::pyo3::exceptions::PyValueError::new_err(
+                        $crate::__private::ToString::to_string(&err),
+                    )
+                })
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "pyo3"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_pyo3 {
+    ($($tt:tt)*) => {};
+}
@@ -0,0 +1,32 @@
+//! `quickcheck::Arbitrary` impl for generated enums, enabled by the `quickcheck` feature.
+
+#[cfg(feature = "quickcheck")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_quickcheck {
+    ($name:ident, $(#[cfg $cfg:tt] $key:ident),*) => {
+        impl $crate::__private::quickcheck::Arbitrary for $name {
+            fn arbitrary(g: &mut $crate::__private::quickcheck::Gen) -> Self {
+                let variants = [$(#[cfg $cfg] $name::$key),*];
+                ::core::clone::Clone::clone(g.choose(&variants).unwrap())
+            }
+
+            fn shrink(&self) -> $crate::__private::Box<dyn ::core::iter::Iterator<Item = Self>> {
+                // Every variant declared before this one, so failures shrink toward the first.
+                let variants = [$(#[cfg $cfg] $name::$key),*];
+                let index = variants
+                    .iter()
+                    .position(|variant| ::core::mem::discriminant(variant) == ::core::mem::discriminant(self))
+                    .unwrap_or(0);
+                $crate::__private::Box::new(variants.into_iter().take(index))
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "quickcheck"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_quickcheck {
+    ($($tt:tt)*) => {};
+}
@@ -0,0 +1,22 @@
+//! `rand` sampling for generated enums, enabled by the `rand` feature.
+
+#[cfg(feature = "rand")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_rand {
+    ($name:ident, $(#[cfg $cfg:tt] $key:ident),*) => {
+        impl $crate::__private::rand::distr::Distribution<$name> for $crate::__private::rand::distr::StandardUniform {
+            fn sample<R: $crate::__private::rand::Rng + ?::core::marker::Sized>(&self, rng: &mut R) -> $name {
+                let index = rng.random_range(0..$name::COUNT);
+                [$(#[cfg $cfg] $name::$key),*].into_iter().nth(index).unwrap()
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "rand"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_rand {
+    ($($tt:tt)*) => {};
+}
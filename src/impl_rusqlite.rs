@@ -0,0 +1,41 @@
+//! `rusqlite` impls for generated enums, enabled by the `rusqlite` feature. The enum is stored
+//! as its string value.
+
+#[cfg(feature = "rusqlite")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_rusqlite {
+    ($name:ident) => {
+        impl $crate::__private::rusqlite::types::ToSql for $name {
+            fn to_sql(
+                &self,
+            ) -> $crate::__private::rusqlite::Result<
+                $crate::__private::rusqlite::types::ToSqlOutput<'_>,
+            > {
+                ::core::result::Result::Ok($crate::__private::rusqlite::types::ToSqlOutput::from(
+                    self.__enum_str_as_str(),
+                ))
+            }
+        }
+
+        impl $crate::__private::rusqlite::types::FromSql for $name {
+            fn column_result(
+                value: $crate::__private::rusqlite::types::ValueRef<'_>,
+            ) -> $crate::__private::rusqlite::types::FromSqlResult<Self> {
+                let value = value.as_str()?;
+                $name::__enum_str_from_str(value).map_err(|err| {
+                    $crate::__private::rusqlite::types::FromSqlError::Other(
+                        $crate::__private::Box::new(err),
+                    )
+                })
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "rusqlite"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_rusqlite {
+    ($($tt:tt)*) => {};
+}
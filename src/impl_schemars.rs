@@ -0,0 +1,49 @@
+//! `schemars::JsonSchema` impl for generated enums, enabled by the `schemars` feature.
+
+#[cfg(feature = "schemars")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_schemars {
+    ($name:ident, [$($other:ident)?], $(#[cfg $cfg:tt] ($key:ident, $value:expr $(, [$($alias:expr),*])*)),*) => {
+        impl $crate::__private::schemars::JsonSchema for $name {
+            fn schema_name() -> $crate::__private::Cow<'static, str> {
+                $crate::__private::Cow::Borrowed(::core::stringify!($name))
+            }
+
+            fn schema_id() -> $crate::__private::Cow<'static, str> {
+                $crate::__private::Cow::Borrowed(::core::concat!(::core::module_path!(), "::", ::core::stringify!($name)))
+            }
+
+            fn json_schema(
+                _: &mut $crate::__private::schemars::SchemaGenerator,
+            ) -> $crate::__private::schemars::Schema {
+                // The aliases parse like the values, so they are listed with them.
+                const STRS: &[&str] = &[$(#[cfg $cfg] $value, $($(#[cfg $cfg] $alias,)*)*)*];
+                $crate::__enum_str_schemars!(@schema [$($other)?], STRS)
+            }
+        }
+    };
+
+    // Strings that only parse once `#[case_insensitive]` or `#[trim]` normalize them are not
+    // listed.
+    (@schema [], $strs:ident) => {
+        $crate::__private::schemars::json_schema!({
+            "type": "string",
+            "enum": $strs,
+        })
+    };
+    // Any string parses into an enum with a catch-all variant.
+    (@schema [$other:ident], $strs:ident) => {
+        $crate::__private::schemars::json_schema!({
+            "type": "string",
+            "examples": $strs,
+        })
+    };
+}
+
+#[cfg(not(feature = "schemars"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_schemars {
+    ($($tt:tt)*) => {};
+}
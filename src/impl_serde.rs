@@ -0,0 +1,139 @@
+//! `Serialize` and `Deserialize` impls for generated enums, enabled by the `serde` feature.
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_serde {
+    ([$($opt:tt)*] $vis:vis $name:ident) => {
+        $crate::__enum_str_serde! { @module [$($opt)*] [$($opt)*] $vis $name }
+
+        $crate::__enum_str_if! { skip(Serialize), [$($opt)*], {}, {
+            impl $crate::__private::serde::Serialize for $name {
+                fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+                where
+                    S: $crate::__private::serde::Serializer,
+                {
+                    serializer.serialize_str(self.__enum_str_as_str())
+                }
+            }
+        } }
+
+        $crate::__enum_str_if! { skip(Deserialize), [$($opt)*], {}, {
+            impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+                fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+                where
+                    D: $crate::__private::serde::Deserializer<'de>,
+                {
+                    $crate::__private::deserialize_from_str(
+                        deserializer,
+                        ::core::stringify!($name),
+                        $crate::__enum_str_serde!(@other [$($opt)*] $name, $crate::__enum_str_serde!(@parse [$($opt)*] $name)),
+                    )
+                }
+            }
+        } }
+    };
+
+    (@module [{serde(module = $module:ident)} $($_opt:tt)*] [$($opt:tt)*] $vis:vis $name:ident) => {
+        #[doc = ::core::concat!(
+            "Serializes [`", ::core::stringify!($name), "`] as its string value, for fields marked ",
+            "`#[serde(with = \"", ::core::stringify!($module), "\")]`."
+        )]
+        $vis mod $module {
+            use super::$name;
+
+            /// Serializes `value` as its string value.
+            pub fn serialize<S>(value: &$name, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                serializer.serialize_str(value.__enum_str_as_str())
+            }
+
+            /// Deserializes a string and parses it like the `Deserialize` impl would.
+            pub fn deserialize<'de, D>(deserializer: D) -> ::core::result::Result<$name, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                $crate::__private::deserialize_from_str(
+                    deserializer,
+                    ::core::stringify!($name),
+                    $crate::__enum_str_serde!(@other [$($opt)*] $name, $crate::__enum_str_serde!(@parse [$($opt)*] $name)),
+                )
+            }
+        }
+    };
+    (@module [$_skip:tt $($rest:tt)*] $opts:tt $vis:vis $name:ident) => {
+        $crate::__enum_str_serde! { @module [$($rest)*] $opts $vis $name }
+    };
+    (@module [] $opts:tt $vis:vis $name:ident) => {};
+
+    (@parse [$($opt:tt)*] $name:ident) => {
+        $crate::__enum_str_if! { serde(names), [$($opt)*], {
+            // Names are only tried when no value matches, before a catch-all.
+            |input: &str| {
+                let val = $crate::__enum_str_if! { trim, [$($opt)*], { input.trim() }, { input } };
+                match $name::__enum_str_variant(val).or_else(|| $name::from_name(val)) {
+                    ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                    ::core::option::Option::None => $name::__enum_str_from_str(input),
+                }
+            }
+        }, { $name::__enum_str_from_str } }
+    };
+
+    (@other [{serde(other = $other:ident)} $($_opt:tt)*] $name:ident, $parse:expr) => {
+        |input: &str| -> ::core::result::Result<$name, $crate::ParseError<$name>> {
+            ::core::result::Result::Ok(($parse)(input).unwrap_or($name::$other))
+        }
+    };
+    (@other [$_skip:tt $($opt:tt)*] $name:ident, $parse:expr) => {
+        $crate::__enum_str_serde!(@other [$($opt)*] $name, $parse)
+    };
+    (@other [] $name:ident, $parse:expr) => { $parse };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_serde {
+    ($($tt:tt)*) => {};
+}
+
+#[cfg(feature = "serde")]
+pub(crate) mod de {
+    use core::fmt;
+    use serde::de::{self, Deserializer, Visitor};
+
+    /// Deserializes a string and parses it with `parse`.
+    pub fn deserialize_from_str<'de, D, T, E>(
+        deserializer: D,
+        name: &'static str,
+        parse: fn(&str) -> Result<T, E>,
+    ) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        E: fmt::Display,
+    {
+        struct StrVisitor<T, E> {
+            name: &'static str,
+            parse: fn(&str) -> Result<T, E>,
+        }
+
+        impl<T, E> Visitor<'_> for StrVisitor<T, E>
+        where
+            E: fmt::Display,
+        {
+            type Value = T;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a {} string", self.name)
+            }
+
+            fn visit_str<Err: de::Error>(self, v: &str) -> Result<T, Err> {
+                (self.parse)(v).map_err(Err::custom)
+            }
+        }
+
+        deserializer.deserialize_str(StrVisitor { name, parse })
+    }
+}
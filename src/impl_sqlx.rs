@@ -0,0 +1,76 @@
+//! `sqlx` impls for generated enums, enabled by the `sqlx` feature. The enum is stored in
+//! whatever text type the database uses for `str`.
+
+#[cfg(feature = "sqlx")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_sqlx {
+    ($name:ident, []) => {
+        $crate::__enum_str_sqlx! { @impl $name, &'q str }
+    };
+    // The value of the catch-all variant is only borrowed from `self`, so it is encoded as an
+    // owned `String`.
+    ($name:ident, [$other:ident]) => {
+        $crate::__enum_str_sqlx! { @impl $name, $crate::__private::String }
+    };
+
+    (@impl $name:ident, $encoded:ty) => {
+        impl<DB: $crate::__private::sqlx::Database> $crate::__private::sqlx::Type<DB> for $name
+        where
+            str: $crate::__private::sqlx::Type<DB>,
+        {
+            fn type_info() -> <DB as $crate::__private::sqlx::Database>::TypeInfo {
+                <str as $crate::__private::sqlx::Type<DB>>::type_info()
+            }
+
+            fn compatible(ty: &<DB as $crate::__private::sqlx::Database>::TypeInfo) -> bool {
+                <str as $crate::__private::sqlx::Type<DB>>::compatible(ty)
+            }
+        }
+
+        impl<'q, DB: $crate::__private::sqlx::Database> $crate::__private::sqlx::Encode<'q, DB>
+            for $name
+        where
+            $encoded: $crate::__private::sqlx::Encode<'q, DB>,
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as $crate::__private::sqlx::Database>::ArgumentBuffer<'q>,
+            ) -> ::core::result::Result<
+                $crate::__private::sqlx::encode::IsNull,
+                $crate::__private::sqlx::error::BoxDynError,
+            > {
+                <$encoded as $crate::__private::sqlx::Encode<'q, DB>>::encode(
+                    ::core::convert::Into::into(self.__enum_str_as_str()),
+                    buf,
+                )
+            }
+
+            fn size_hint(&self) -> usize {
+                <$encoded as $crate::__private::sqlx::Encode<'q, DB>>::size_hint(
+                    &::core::convert::Into::into(self.__enum_str_as_str()),
+                )
+            }
+        }
+
+        impl<'r, DB: $crate::__private::sqlx::Database> $crate::__private::sqlx::Decode<'r, DB>
+            for $name
+        where
+            &'r str: $crate::__private::sqlx::Decode<'r, DB>,
+        {
+            fn decode(
+                value: <DB as $crate::__private::sqlx::Database>::ValueRef<'r>,
+            ) -> ::core::result::Result<Self, $crate::__private::sqlx::error::BoxDynError> {
+                let value = <&'r str as $crate::__private::sqlx::Decode<'r, DB>>::decode(value)?;
+                ::core::result::Result::Ok($name::__enum_str_from_str(value)?)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "sqlx"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_sqlx {
+    ($($tt:tt)*) => {};
+}
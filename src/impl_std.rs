@@ -0,0 +1,78 @@
+//! Impls for generated enums that need the standard library, enabled by the `std` feature.
+
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_std {
+    ($name:ident) => {
+        impl ::core::convert::TryFrom<&$crate::__private::OsStr> for $name {
+            type Error = $crate::ParseError<$name>;
+
+            fn try_from(
+                val: &$crate::__private::OsStr,
+            ) -> ::core::result::Result<Self, $crate::ParseError<$name>> {
+                $name::__enum_str_from_bytes(val.as_encoded_bytes())
+            }
+        }
+    };
+
+    (@env [$($opt:tt)*] $vis:vis $name:ident) => {
+        #[allow(dead_code)]
+        impl $name {
+            $crate::__enum_str_item! { from_env, [$($opt)*], $vis,
+                { /// Parses the environment variable `var`, with surrounding ASCII whitespace trimmed.
+                } { fn } {
+                    (var: &str) -> ::core::result::Result<Self, $crate::EnvError<Self>> {
+                        match ::std::env::var_os(var) {
+                            ::core::option::Option::Some(value) => {
+                                $name::__enum_str_from_bytes(value.as_encoded_bytes().trim_ascii())
+                                    .map_err(|err| $crate::EnvError::invalid(var, err))
+                            }
+                            ::core::option::Option::None => ::core::result::Result::Err($crate::EnvError::not_present(var)),
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_std {
+    ($($tt:tt)*) => {};
+}
+
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_hash_map {
+    ($name:ident, $val:expr, $(#[cfg $cfg:tt] ($key:ident, $value:expr $(, [$($alias:expr),*])*)),*) => {{
+        type Map = $crate::__private::HashMap<&'static str, fn() -> $name>;
+        static MAP: $crate::__private::OnceLock<Map> = $crate::__private::OnceLock::new();
+        let map = MAP.get_or_init(|| {
+            const ENTRIES: &[(&str, fn() -> $name)] = &[
+                $(
+                    #[cfg $cfg]
+                    ($value, || $name::$key),
+                    $($(#[cfg $cfg] ($alias, || $name::$key),)*)*
+                )*
+            ];
+            ENTRIES.iter().copied().collect()
+        });
+        match map.get($val) {
+            ::core::option::Option::Some(variant) => ::core::option::Option::Some(variant()),
+            ::core::option::Option::None => ::core::option::Option::None,
+        }
+    }};
+}
+
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_hash_map {
+    ($($tt:tt)*) => {
+        ::core::compile_error!("`#[hash_map]` requires the `std` feature of enum_str")
+    };
+}
@@ -0,0 +1,31 @@
+//! `strum` trait impls for generated enums, enabled by the `strum` feature.
+
+#[cfg(feature = "strum")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_strum {
+    ($name:ident, $(#[cfg $cfg:tt] $key:ident),*) => {
+        impl $crate::__private::strum::IntoEnumIterator for $name {
+            type Iterator = ::core::array::IntoIter<Self, { $name::COUNT }>;
+
+            fn iter() -> Self::Iterator {
+                [$(#[cfg $cfg] $name::$key),*].into_iter()
+            }
+        }
+
+        impl $crate::__private::strum::EnumCount for $name {
+            const COUNT: usize = $name::COUNT;
+        }
+
+        impl $crate::__private::strum::VariantNames for $name {
+            const VARIANTS: &'static [&'static str] = $name::VALUES;
+        }
+    };
+}
+
+#[cfg(not(feature = "strum"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_strum {
+    ($($tt:tt)*) => {};
+}
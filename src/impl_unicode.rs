@@ -0,0 +1,32 @@
+//! Matching after Unicode normalization, used by enums marked `#[nfc]` when the
+//! `unicode-normalization` feature is enabled.
+
+#[cfg(feature = "unicode-normalization")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_nfc {
+    (
+        $name:ident, $val:expr, $ignore_case:expr, $collapse:expr,
+        $(#[cfg $cfg:tt] ($key:ident, $value:expr $(, [$($alias:expr),*])*)),*
+    ) => {
+        match $val {
+            $(
+                #[cfg $cfg]
+                v if $crate::__private::eq_nfc(v, $value, $ignore_case, $collapse)
+                    $($(|| $crate::__private::eq_nfc(v, $alias, $ignore_case, $collapse))*)* => {
+                    ::core::option::Option::Some($name::$key)
+                }
+            )*
+            _ => ::core::option::Option::None,
+        }
+    };
+}
+
+#[cfg(not(feature = "unicode-normalization"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_nfc {
+    ($($tt:tt)*) => {
+        ::core::compile_error!("`#[nfc]` requires the `unicode-normalization` feature of enum_str")
+    };
+}
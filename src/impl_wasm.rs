@@ -0,0 +1,54 @@
+//! `JsValue` conversions for generated enums, enabled by the `wasm` feature. The enum crosses
+//! the wasm boundary as a JS string holding its value.
+
+#[cfg(feature = "wasm")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_wasm {
+    ($name:ident) => {
+        impl ::core::convert::From<$name> for $crate::__private::wasm_bindgen::JsValue {
+            fn from(value: $name) -> Self {
+                $crate::__private::wasm_bindgen::JsValue::from_str(value.__enum_str_as_str())
+            }
+        }
+
+        impl ::core::convert::TryFrom<&$crate::__private::wasm_bindgen::JsValue> for $name {
+            type Error = $crate::__private::wasm_bindgen::JsValue;
+
+            fn try_from(
+                value: &$crate::__private::wasm_bindgen::JsValue,
+            ) -> ::core::result::Result<Self, $crate::__private::wasm_bindgen::JsValue> {
+                let value = value.as_string().ok_or_else(|| {
+                    $crate::__private::wasm_bindgen::JsError::new(::core::concat!(
+                        "expected a ",
+                        ::core::stringify!($name),
+                        " string"
+                    ))
+                })?;
+                $name::__enum_str_from_str(&value).map_err(|err| {
+                    $crate::__private::wasm_bindgen::JsError::new(
+                        &$crate::__private::ToString::to_string(&err),
+                    )
+                    .into()
+                })
+            }
+        }
+
+        impl ::core::convert::TryFrom<$crate::__private::wasm_bindgen::JsValue> for $name {
+            type Error = $crate::__private::wasm_bindgen::JsValue;
+
+            fn try_from(
+                value: $crate::__private::wasm_bindgen::JsValue,
+            ) -> ::core::result::Result<Self, $crate::__private::wasm_bindgen::JsValue> {
+                $name::try_from(&value)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "wasm"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_wasm {
+    ($($tt:tt)*) => {};
+}
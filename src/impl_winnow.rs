@@ -0,0 +1,33 @@
+//! A `winnow` parser for generated enums, enabled by the `winnow` feature.
+
+#[cfg(feature = "winnow")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_winnow {
+    ($vis:vis $name:ident) => {
+        #[allow(dead_code)]
+        impl $name {
+            /// Parses the longest value or alias at the start of `input`. The function
+            /// implements `winnow::Parser`, so it can be used in `winnow` grammars.
+            $vis fn parse_next<'i, E>(input: &mut &'i str) -> ::core::result::Result<Self, E>
+            where
+                E: $crate::__private::winnow::error::ParserError<&'i str>,
+            {
+                match $name::match_longest_at(input) {
+                    ::core::option::Option::Some((variant, len)) => {
+                        *input = &input[len..];
+                        ::core::result::Result::Ok(variant)
+                    }
+                    ::core::option::Option::None => ::core::result::Result::Err(E::from_input(&*input)),
+                }
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "winnow"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_winnow {
+    ($($tt:tt)*) => {};
+}
@@ -1,8 +1,71 @@
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+#[doc(hidden)]
+pub mod __private;
+mod error;
+mod error_enum;
+mod impl_alloc;
+mod impl_arbitrary;
+mod impl_clap;
+mod impl_diesel;
+mod impl_nom;
+mod impl_phf;
+mod impl_postgres;
+mod impl_proptest;
+mod impl_pyo3;
+mod impl_quickcheck;
+mod impl_rand;
+mod impl_rusqlite;
+mod impl_schemars;
+mod impl_serde;
+mod impl_sqlx;
+mod impl_std;
+mod impl_strum;
+mod impl_unicode;
+mod impl_wasm;
+mod impl_winnow;
+mod locale;
+mod map;
+mod mapping;
+mod set;
+mod suite;
+
+#[cfg(feature = "std")]
+pub use error::EnvError;
+#[cfg(feature = "alloc")]
+pub use error::ListError;
+pub use error::{IndexError, ParseError};
+pub use mapping::Mapping;
+
+/// Derive macro for an enum declared the usual way. See the `enum_str_derive` crate.
+#[cfg(feature = "derive")]
+pub use enum_str_derive::EnumStr;
+
+/// Declares an enum with [`enum_str!`], reading its variants from a JSON or CSV file. See the
+/// `enum_str_derive` crate.
+#[cfg(feature = "derive")]
+pub use enum_str_derive::include_enum_str;
+
+/// The `#[enum_str]` attribute, which adds the generated surface to an existing enum.
+///
+/// It lives in its own module since it shares its name with the [`enum_str!`] macro.
+#[cfg(feature = "derive")]
+pub mod attr {
+    pub use enum_str_derive::enum_str;
+}
+
 /// Macro used to generate an enum with a `FromStr` trait implementation and `as_str` method.
 ///
 /// The enum is identified by the `name` passed to the macro. Enum values are identified by
 /// the `key` passed in each tuple. The 'value' is used as the string representation for
-/// FromStr and AsStr traits.
+/// FromStr and AsStr traits. Strings that match no value fail to parse with a [`ParseError`],
+/// whose message lists the accepted values. `Display` prints the value, or the identifier of
+/// the variant in its alternate form `{:#}`.
 ///
 /// # Example
 /// ```
@@ -18,8 +81,32 @@
 ///
 /// assert_eq!("🍎", Fruit::Apple.as_str());
 /// assert_eq!("🍎", Fruit::Apple.to_string().as_str());
+/// assert_eq!("Apple", format!("{:#}", Fruit::Apple));
 /// assert_eq!(Fruit::Apple, Fruit::from_str("🍎").unwrap());
 /// assert_eq!(Fruit::Apple, "🍎".parse().unwrap());
+/// assert_eq!("Apple", Fruit::Apple.name());
+/// assert_eq!(Some(Fruit::Apple), Fruit::from_name("Apple"));
+/// assert!(Fruit::is_valid("🍍"));
+/// assert_eq!(Some((Fruit::Apple, " pie")), Fruit::strip_prefix("🍎 pie"));
+/// assert_eq!(Some((Fruit::Apple, 4)), Fruit::match_longest_at("🍎 pie"));
+/// assert_eq!(vec![(4, Fruit::Pineapple)], Fruit::find_all("pie 🍍").collect::<Vec<_>>());
+/// // SAFETY: "🍓" is a value of `Fruit`.
+/// assert_eq!(Fruit::Strawberry, unsafe { Fruit::from_str_unchecked("🍓") });
+///
+/// let all: Vec<&str> = Fruit::iter().map(|fruit| fruit.as_str()).collect();
+/// assert_eq!(vec!["🍎", "🍍", "🍓"], all);
+/// assert_eq!(&["🍎", "🍍", "🍓"], Fruit::VALUES);
+/// assert_eq!((Fruit::Pineapple, "🍍"), Fruit::TABLE[1]);
+/// assert_eq!(Fruit::Strawberry, Fruit::VARIANTS[2]);
+/// assert_eq!(
+///     [Fruit::Pineapple, Fruit::Apple, Fruit::Strawberry],
+///     Fruit::sorted_variants()
+/// );
+/// assert_eq!(2, Fruit::Strawberry.index());
+/// assert_eq!(Some(Fruit::Pineapple), Fruit::from_index(1));
+/// assert_eq!(Ok(Fruit::Pineapple), Fruit::try_from(1_u32));
+/// assert_eq!(Some(Fruit::Pineapple), Fruit::Apple.next());
+/// assert_eq!(Fruit::Strawberry, Fruit::Apple.prev_wrapping());
 /// ```
 ///
 /// You can also set the visibility of enums.
@@ -36,80 +123,4475 @@
 ///     }
 /// }
 /// ```
-#[macro_export]
-macro_rules! enum_str {
-    ($vis:vis $name:ident, $(($key:ident, $value:expr),)*) => {
-        #[derive(Debug, Clone, PartialEq)]
-       $vis enum $name
-        {
-            $($key),*
-        }
-
-        impl $name {
-            $vis fn as_str(&self) -> &str {
-                match self {
-                    $(
-                        &$name::$key => $value
-                    ),*
-                }
-            }
-        }
-
-        impl std::fmt::Display for $name {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                match self {
-                    $(
-                        &$name::$key => write!(f, "{}", $value)
-                    ),*
-                }
-            }
-        }
-
-        impl std::str::FromStr for $name {
-            type Err = ();
-
-            fn from_str(val: &str) -> Result<Self, Self::Err> {
-                match val {
-                    $(
-                        $value => Ok($name::$key)
-                    ),*,
-                    _ => Err(())
-                }
-            }
-        }
-    }
-}
-
-#[cfg(test)]
-mod test {
-    use super::enum_str;
-    use std::str::FromStr;
-
-    enum_str! {
-        Fruit,
-        (Apple, "🍎"),
-        (Pineapple, "🍍"),
-        (Strawberry, "🍓"),
-    }
-
-    #[test]
-    fn test_as_str() {
-        assert_eq!("🍎", Fruit::Apple.as_str());
-    }
-
-    #[test]
-    fn test_to_string() {
-        assert_eq!("🍎", Fruit::Apple.to_string().as_str());
-    }
-
-    #[test]
-    fn test_from_str_ok() {
-        assert_eq!(Fruit::Apple, Fruit::from_str("🍎").unwrap());
-        assert_eq!(Fruit::Strawberry, Fruit::from_str("🍓").unwrap());
-    }
-
-    #[test]
-    fn test_from_str_err() {
-        assert!(Fruit::from_str("Strawberry").is_err());
+///
+/// Values and aliases can be any constant expression of type `&'static str`, such as a `const`,
+/// a `static` or a `concat!`, except with `#[phf]`, which needs literals. The values are read
+/// at compile time, so a `static` cannot be `mut`.
+/// ```
+/// use enum_str::enum_str;
+///
+/// const PREFIX: &str = "fruit:";
+/// static APPLE: &str = "fruit:apple";
+///
+/// enum_str! {
+///     Fruit,
+///     (Apple, APPLE),
+///     (Pineapple, concat!("fruit:", "pineapple"), aliases = [PREFIX]),
+/// }
+///
+/// assert_eq!("fruit:apple", Fruit::Apple.as_str());
+/// assert_eq!(Ok(Fruit::Pineapple), "fruit:".parse());
+/// ```
+///
+/// The enum derives `Debug`, `Clone` and `PartialEq` by default. Pass a `#[derive(...)]`
+/// before the name to replace that list.
+/// ```
+/// use std::collections::HashSet;
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+///     Fruit,
+///     (Apple, "🍎"),
+///     (Pineapple, "🍍"),
+///     (Strawberry, "🍓"),
+/// }
+///
+/// let basket: HashSet<Fruit> = [Fruit::Apple, Fruit::Apple].into_iter().collect();
+/// assert_eq!(1, basket.len());
+/// assert!(Fruit::Apple < Fruit::Strawberry);
+/// ```
+///
+/// Doc comments and other attributes can be placed on the enum and on each variant. The
+/// generated docs add the string value of each variant, and a table of all of them to the
+/// enum. A public enum can be marked `#[non_exhaustive]`, so adding a variant later is not a
+/// breaking change.
+/// ```
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     /// Fruit sold at the market.
+///     #[allow(clippy::enum_variant_names)]
+///     #[non_exhaustive]
+///     pub Fruit,
+///     /// Crisp and red.
+///     (Apple, "🍎"),
+///     #[doc(alias = "ananas")]
+///     (Pineapple, "🍍"),
+///     (Strawberry, "🍓"),
+/// }
+///
+/// assert_eq!("🍓", Fruit::Strawberry.as_str());
+/// ```
+///
+/// Starting with `mod name,` declares the enum and everything generated for it inside a new
+/// module, which imports the items of the surrounding one. Attributes before `mod` go to the
+/// module. The enum needs to be `pub` or `pub(super)` to be used from outside the module.
+/// ```
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     /// Fruit and its string mapping.
+///     pub mod fruit,
+///     #[derive(Debug, Clone, Copy, PartialEq)]
+///     pub Fruit,
+///     (Apple, "🍎"),
+///     (Pineapple, "🍍"),
+/// }
+///
+/// use fruit::Fruit;
+///
+/// assert_eq!(Fruit::Apple, "🍎".parse().unwrap());
+/// assert_eq!(2, Fruit::COUNT);
+/// ```
+///
+/// `impl Name { ... }` generates the methods and trait implementations for an enum declared
+/// elsewhere in the crate, such as one generated by prost or bindgen, without declaring it.
+/// The visibility in front of `impl` applies to the generated methods. The enum needs the
+/// `Debug`, `Clone` and `PartialEq` derives the macro adds by default; attributes that only
+/// affect the declaration, like `#[derive(...)]` and doc comments, are ignored.
+/// ```
+/// use enum_str::enum_str;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// pub enum Fruit {
+///     Apple,
+///     Pineapple,
+/// }
+///
+/// enum_str! {
+///     pub impl Fruit {
+///         Apple => "🍎",
+///         Pineapple => "🍍",
+///     }
+/// }
+///
+/// assert_eq!("🍎", Fruit::Apple.as_str());
+/// assert_eq!(Fruit::Pineapple, "🍍".parse().unwrap());
+/// assert_eq!("🍍", Fruit::Pineapple.to_string());
+/// ```
+///
+/// A `#[cfg(...)]` on a variant removes it from the enum and from every generated method when
+/// the condition is false, so its value no longer parses.
+/// ```
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     Fruit,
+///     (Apple, "🍎"),
+///     #[cfg(feature = "experimental")]
+///     (Durian, "durian"),
+/// }
+///
+/// # #[cfg(not(feature = "experimental"))]
+/// assert!("durian".parse::<Fruit>().is_err());
+/// ```
+///
+/// `#[skip(...)]` leaves out some of the generated trait impls, for example to write a
+/// custom `Display`. `Display`, `FromStr`, `AsRef`, `From`, `TryFrom`, `Serialize` and
+/// `Deserialize` can be skipped. The other impls keep parsing the same way without `FromStr`.
+/// ```
+/// use core::fmt;
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     #[skip(Display)]
+///     Fruit,
+///     (Apple, "🍎"),
+///     (Pineapple, "🍍"),
+/// }
+///
+/// impl fmt::Display for Fruit {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         write!(f, "{} ({})", self.name(), self.as_str())
+///     }
+/// }
+///
+/// assert_eq!("Apple (🍎)", Fruit::Apple.to_string());
+/// ```
+///
+/// `#[rename(as_str = ...)]` gives the `as_str` method another name, leaving `as_str` free for
+/// a method of your own. The generated impls are not affected by the rename. The `parse`
+/// function of the `nom` feature can be renamed the same way, with `#[rename(parse = ...)]`.
+/// ```
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     #[rename(as_str = as_code)]
+///     Status,
+///     (Ok, "OK"),
+///     (NotFound, "NOT_FOUND"),
+/// }
+///
+/// assert_eq!("NOT_FOUND", Status::NotFound.as_code());
+/// assert_eq!("OK", Status::Ok.to_string());
+/// ```
+///
+/// The generated methods and constants share the visibility of the enum. `#[vis(...)]` gives
+/// single ones a different visibility, `pub(self)` making them private to the module.
+/// `VARIANTS`, `VALUES`, `TABLE`, `COUNT`, `MAX_STR_LEN`, `MIN_STR_LEN`, `PATTERN`,
+/// `as_str`, `as_short_str`, `as_long_str`, `name`, `from_name`, `as_bytes`, `as_char`,
+/// `from_char`, `from_str_ignore_ascii_case`, `is_valid`, `from_str_unchecked`,
+/// `strip_prefix`, `match_longest_at`, `parse`, `find_all`, `code`, `from_code`, `from_env`,
+/// `parse_list`, `join`, `mapping`, `index`, `from_index`, `next`, `prev`, `next_wrapping`,
+/// `prev_wrapping`, `cmp_str`, `sorted_variants`, `as_str_locale`, `from_str_any_locale` and
+/// `iter` can be listed.
+/// ```compile_fail
+/// mod http {
+///     use enum_str::enum_str;
+///
+///     enum_str! {
+///         #[vis(from_name = pub(self), COUNT = pub(crate))]
+///         pub Method,
+///         (Get, "GET"),
+///     }
+/// }
+///
+/// assert_eq!("GET", http::Method::Get.as_str());
+/// assert_eq!(1, http::Method::COUNT);
+/// http::Method::from_name("Get"); // error: `from_name` is private
+/// ```
+///
+/// `#[error(MyError)]` makes `MyError` the error of `FromStr` and `TryFrom<&str>`, converting
+/// the `ParseError` with `From`. `#[error(MyError, with = path)]` calls `path` with the
+/// rejected input instead.
+/// ```
+/// use enum_str::enum_str;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum ConfigError {
+///     UnknownOption(String),
+/// }
+///
+/// impl ConfigError {
+///     fn unknown_option(input: &str) -> Self {
+///         ConfigError::UnknownOption(input.to_string())
+///     }
+/// }
+///
+/// enum_str! {
+///     #[error(ConfigError, with = ConfigError::unknown_option)]
+///     LogLevel,
+///     (Info, "info"),
+///     (Debug, "debug"),
+/// }
+///
+/// assert_eq!(
+///     Err(ConfigError::UnknownOption("trace".to_string())),
+///     "trace".parse::<LogLevel>(),
+/// );
+/// ```
+///
+/// Variants without aliases or options can also be written like the variants of an ordinary
+/// enum, as `Key = "value"` or `Key => "value"`. One enum uses one of the forms throughout.
+/// In every form the comma after the last variant is optional.
+/// ```
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     Fruit,
+///     Apple = "🍎",
+///     Pineapple = "🍍",
+/// }
+///
+/// enum_str! {
+///     Color,
+///     Red => "red",
+///     Green => "green"
+/// }
+///
+/// assert_eq!("🍍", Fruit::Pineapple.as_str());
+/// assert_eq!(Color::Green, "green".parse().unwrap());
+/// ```
+///
+/// Several enums can be declared in one invocation by wrapping them in an `enum { ... }` block.
+/// The attributes and the visibility in front of the block apply to every enum in it.
+/// ```
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     #[derive(Debug, Clone, Copy, PartialEq)]
+///     #[case_insensitive]
+///     pub enum {
+///         Suit {
+///             (Hearts, "hearts"),
+///             (Spades, "spades"),
+///         }
+///         /// The rank of a face card.
+///         Face {
+///             (Jack, "J"),
+///             (Queen, "Q"),
+///             (King, "K"),
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Suit::Spades, "SPADES".parse().unwrap());
+/// assert_eq!(Face::King, "k".parse().unwrap());
+/// ```
+///
+/// Adding `#[case_insensitive]` makes `from_str` compare the input and the values after Unicode
+/// case folding, so any casing of a value is accepted, including "STRASSE" for "straße".
+/// ```
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     #[case_insensitive]
+///     Method,
+///     (Get, "GET"),
+///     (Post, "Post"),
+///     (Delete, "delete"),
+/// }
+///
+/// assert_eq!(Method::Get, "get".parse().unwrap());
+/// assert_eq!(Method::Post, "POST".parse().unwrap());
+/// assert_eq!(Method::Delete, "DeLeTe".parse().unwrap());
+/// ```
+///
+/// Without it, `from_str_ignore_ascii_case` still offers a lenient parse next to the strict
+/// `from_str`, ignoring the case of ASCII letters only.
+/// ```
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     Method,
+///     (Get, "GET"),
+///     (Post, "POST"),
+/// }
+///
+/// assert!("get".parse::<Method>().is_err());
+/// assert_eq!(Some(Method::Get), Method::from_str_ignore_ascii_case("get"));
+/// ```
+///
+/// `#[trim]` strips leading and trailing whitespace from the input before matching it, and
+/// `#[trim(collapse)]` also treats every run of whitespace inside it as a single space.
+/// ```
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     #[trim(collapse)]
+///     Weather,
+///     (Sunny, "sunny"),
+///     (PartlyCloudy, "partly cloudy"),
+/// }
+///
+/// assert_eq!(Weather::Sunny, "  sunny\n".parse().unwrap());
+/// assert_eq!(Weather::PartlyCloudy, "partly \t cloudy".parse().unwrap());
+/// ```
+///
+/// `#[char]` enums use single characters as values. They additionally get `as_char`, a
+/// `const fn from_char` matching the character exactly and `TryFrom<char>`, which parses it like
+/// a one-char string. Their values can be constant `char` expressions too.
+/// ```
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     #[char]
+///     Piece,
+///     (King, 'K'),
+///     (Queen, 'Q'),
+///     (Pawn, 'P', aliases = ['p']),
+/// }
+///
+/// assert_eq!('K', Piece::King.as_char());
+/// assert_eq!("Q", Piece::Queen.as_str());
+/// assert_eq!(Some(Piece::Queen), Piece::from_char('Q'));
+/// assert_eq!(Ok(Piece::Pawn), Piece::try_from('p'));
+/// assert_eq!(Piece::King, "K".parse().unwrap());
+/// ```
+///
+/// The attribute is required: without it, a `char` value is reported as an error that
+/// points to `#[char]`.
+/// ```compile_fail
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     Piece,
+///     (King, 'K'),
+/// }
+/// ```
+///
+/// `index` and `from_index` convert between the variants and their position in declaration
+/// order, and `TryFrom` is implemented for `u8`, `u16`, `u32`, `u64` and `usize` with the
+/// same mapping, failing with an [`IndexError`]. The position is not the discriminant, which
+/// `#[repr(...)]` converts instead.
+/// ```
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     Level,
+///     (Low, "low"),
+///     (High, "high"),
+/// }
+///
+/// assert_eq!(1, Level::High.index());
+/// assert_eq!(Ok(Level::High), Level::try_from(1_u32));
+/// assert!(Level::try_from(2_usize).is_err());
+/// ```
+///
+/// Values can be read from raw bytes with `TryFrom<&[u8]>`, which compares them with the values
+/// without checking them for UTF-8 first, unless the enum is `#[case_insensitive]`, `#[trim]` or
+/// `#[nfc]`.
+/// Its error is always a [`ParseError`]. `as_bytes` returns the value as bytes. With the
+/// `alloc` feature, `TryFrom<String>` parses an owned string and gives it back as the error
+/// when it matches no variant.
+/// ```
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     Method,
+///     (Get, "GET"),
+///     (Post, "POST"),
+/// }
+///
+/// assert_eq!(Ok(Method::Post), Method::try_from(&b"POST"[..]));
+/// assert!(Method::try_from(&b"\xff"[..]).is_err());
+/// assert_eq!(b"GET", Method::Get.as_bytes());
+/// ```
+///
+/// `#[suggest]` makes a failed parse name the closest value when the input looks like a typo
+/// of it, which helps on the command line. The suggestion needs the `alloc` feature.
+/// ```
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     #[suggest]
+///     Command,
+///     (Build, "build"),
+///     (Check, "check"),
+/// }
+///
+/// let err = "chek".parse::<Command>().unwrap_err();
+/// # #[cfg(feature = "alloc")]
+/// assert_eq!("unknown Command: \"chek\", did you mean \"check\"?", err.to_string());
+/// ```
+///
+/// A variant can be parsed from additional strings by listing them as `aliases`. The first
+/// value stays the one returned by `as_str` and `Display`.
+/// ```
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     Fruit,
+///     (Apple, "🍎", aliases = ["apple", "red-apple"]),
+///     (Pineapple, "🍍"),
+/// }
+///
+/// assert_eq!(Fruit::Apple, "apple".parse().unwrap());
+/// assert_eq!(Fruit::Apple, "red-apple".parse().unwrap());
+/// assert_eq!("🍎", Fruit::Apple.as_str());
+/// ```
+///
+/// `PATTERN` holds the values and aliases as a regex alternation, longest first and with the
+/// regex metacharacters escaped, for embedding into larger regexes. It ignores options like
+/// `#[case_insensitive]`, which the surrounding regex has to apply.
+/// ```
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     Level,
+///     (Info, "info", aliases = ["i"]),
+///     (Warn, "warn", aliases = ["warn+"]),
+/// }
+///
+/// assert_eq!(r"warn\+|info|warn|i", Level::PATTERN);
+/// ```
+///
+/// Marking one variant `default` implements `Default` for the enum.
+/// ```
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     Mode,
+///     (Light, "light", default),
+///     (Dark, "dark", aliases = ["night"]),
+/// }
+///
+/// assert_eq!(Mode::Light, Mode::default());
+/// ```
+///
+/// A final `_ => Other` entry adds an `Other(String)` variant that catches every input matching
+/// no value, so parsing never fails and the text round-trips through `as_str` and `Display`.
+/// `as_str` then borrows from the enum instead of returning a `&'static str`, and the enum can
+/// neither be `Copy` nor have a `#[repr]`. The catch-all variant needs the `alloc` feature.
+#[cfg_attr(feature = "alloc", doc = "```")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     Method,
+///     (Get, "GET"),
+///     (Post, "POST"),
+///     _ => Other,
+/// }
+///
+/// assert_eq!(Method::Get, "GET".parse().unwrap());
+/// let method: Method = "PURGE".parse().unwrap();
+/// assert_eq!(Method::Other(String::from("PURGE")), method);
+/// assert_eq!("PURGE", method.as_str());
+/// assert_eq!(&[Method::Get, Method::Post], Method::VARIANTS);
+/// ```
+///
+/// Every value and alias has to be unique, so two variants sharing a string fail to compile,
+/// just like two variants sharing a name.
+/// ```compile_fail
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     Fruit,
+///     (Apple, "🍎"),
+///     (RedApple, "🍎"),
+/// }
+/// ```
+///
+/// Input that does not parse is checked for common mistakes, such as a missing `,` between
+/// two variants or a variant without a value, which are reported with a message naming the
+/// variant.
+/// ```compile_fail
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     Fruit,
+///     (Apple, "🍎") // error: expected a `,` after `Fruit::Apple`
+///     (Pineapple, "🍍"),
+/// }
+/// ```
+///
+/// `#[eq_str]` implements `PartialEq` between the enum and `str`, in both directions. It is
+/// opt-in because the extra impls stop `assert_eq!(Fruit::Apple, "🍎".parse().unwrap())`
+/// from inferring the parsed type.
+/// ```
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     #[eq_str]
+///     Fruit,
+///     (Apple, "🍎"),
+///     (Pineapple, "🍍"),
+/// }
+///
+/// assert!(Fruit::Apple == "🍎");
+/// assert!("🍍" == Fruit::Pineapple);
+/// ```
+///
+/// `#[borrow_str]` implements `Borrow<str>`, and `Hash` through the string value so the two
+/// agree, letting a `HashMap` keyed by the enum be queried with a `&str`. Derive `Eq` but not
+/// `Hash` when using it.
+/// ```
+/// use std::collections::HashMap;
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     #[borrow_str]
+///     #[derive(Debug, Clone, PartialEq, Eq)]
+///     Fruit,
+///     (Apple, "🍎"),
+///     (Pineapple, "🍍"),
+/// }
+///
+/// let prices = HashMap::from([(Fruit::Apple, 3), (Fruit::Pineapple, 5)]);
+/// assert_eq!(Some(&3), prices.get("🍎"));
+/// ```
+///
+/// `#[debug_str]` implements `Debug` to print the string value next to the identifier, as
+/// `Apple("🍎")`. The default derive list leaves out `Debug` when it is used.
+/// ```
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     #[debug_str]
+///     Fruit,
+///     (Apple, "0x1F34E"),
+///     (Pineapple, "0x1F34D"),
+/// }
+///
+/// assert_eq!(r#"Apple("0x1F34E")"#, format!("{:?}", Fruit::Apple));
+/// ```
+///
+/// Variants can be given explicit discriminants. With a `#[repr(u8)]` (or any other integer
+/// type), `as_u8` and `try_from_u8` convert between the variants and their discriminants.
+/// ```
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     #[repr(u8)]
+///     Opcode,
+///     (Load = 3, "LOAD"),
+///     (Store, "STORE"),
+///     (Halt = 255, "HALT"),
+/// }
+///
+/// assert_eq!(4, Opcode::Store.as_u8());
+/// assert_eq!(Some(Opcode::Halt), Opcode::try_from_u8(255));
+/// assert_eq!(None, Opcode::try_from_u8(0));
+/// assert_eq!("LOAD", Opcode::try_from_u8(3).unwrap().as_str());
+/// ```
+///
+/// `#[map(FruitMap)]` also declares `FruitMap<V>`, a map from every variant to a `V` stored in
+/// an array. It is built with `from_fn` or `Default`, indexed by the variants and iterated in
+/// declaration order.
+/// ```
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     #[map(FruitMap)]
+///     #[derive(Debug, Clone, Copy, PartialEq)]
+///     Fruit,
+///     (Apple, "🍎"),
+///     (Pineapple, "🍍"),
+/// }
+///
+/// let mut stock = FruitMap::from_fn(|fruit| fruit.as_str().len());
+/// stock[Fruit::Apple] += 1;
+/// assert_eq!(5, stock[Fruit::Apple]);
+/// assert_eq!(vec![(Fruit::Apple, &5), (Fruit::Pineapple, &4)], stock.iter().collect::<Vec<_>>());
+/// ```
+///
+/// `#[set(FruitSet)]` declares `FruitSet`, a set of variants stored as the bits of a `u64`, or
+/// of the integer type given as in `#[set(FruitSet, u8)]`. It parses from and formats as the
+/// values separated by commas.
+/// ```
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     #[set(Permissions, u8)]
+///     Permission,
+///     (Read, "read"),
+///     (Write, "write"),
+///     (Execute, "execute"),
+/// }
+///
+/// let mut permissions: Permissions = "read, execute".parse().unwrap();
+/// assert!(permissions.contains(&Permission::Execute));
+/// permissions.insert(Permission::Write);
+/// assert_eq!(0b111, permissions.bits());
+/// assert_eq!("read,write,execute", permissions.to_string());
+/// ```
+///
+/// An integer after the value of every variant is its code, which `code` returns as a `u16`
+/// and `from_code` converts back. Codes are not parsed by `FromStr`.
+/// ```
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     Status,
+///     (Ok, "OK", 200),
+///     (NotFound, "Not Found", 404, aliases = ["NOT_FOUND"]),
+/// }
+///
+/// assert_eq!(404, Status::NotFound.code());
+/// assert_eq!(Some(Status::Ok), Status::from_code(200));
+/// assert_eq!(None, Status::from_code(500));
+/// assert_eq!(Status::NotFound, "NOT_FOUND".parse().unwrap());
+/// ```
+///
+/// Besides `aliases`, a variant can list `short = [...]` and `long = [...]` representations.
+/// They are parsed like aliases, and `as_short_str` and `as_long_str` return the first of
+/// them, or the value of a variant without one. The methods are only generated when a
+/// variant lists such a representation.
+/// ```
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     Weekday,
+///     (Monday, "Monday", short = ["Mon"], long = ["Monday, the first day"]),
+///     (Tuesday, "Tuesday", short = ["Tue", "Tues"]),
+/// }
+///
+/// assert_eq!("Mon", Weekday::Monday.as_short_str());
+/// assert_eq!("Tuesday", Weekday::Tuesday.as_long_str());
+/// assert_eq!(Weekday::Tuesday, "Tues".parse().unwrap());
+/// assert_eq!("Tuesday", Weekday::Tuesday.to_string());
+/// ```
+///
+/// `#[locale(Language)]` lets variants give their value in other locales, each a variant of the
+/// `Language` enum, as in `locale(De = "Apfel")`. `as_str_locale` returns the value in a
+/// locale, falling back to the value of the variant, and `from_str_any_locale` parses a value
+/// in any of them. Only the values of the variant themselves are parsed by `FromStr`, so
+/// different locales may share a value.
+/// ```
+/// use enum_str::enum_str;
+///
+/// enum Language {
+///     En,
+///     De,
+///     Fr,
+/// }
+///
+/// enum_str! {
+///     #[locale(Language)]
+///     Fruit,
+///     (Apple, "apple", locale(De = "Apfel", Fr = "pomme")),
+///     (Kiwi, "kiwi", locale(De = "Kiwi")),
+/// }
+///
+/// assert_eq!("Apfel", Fruit::Apple.as_str_locale(Language::De));
+/// assert_eq!("kiwi", Fruit::Kiwi.as_str_locale(Language::Fr));
+/// assert_eq!("apple", Fruit::Apple.as_str_locale(Language::En));
+/// assert_eq!(Some(Fruit::Apple), Fruit::from_str_any_locale("pomme"));
+/// assert!("pomme".parse::<Fruit>().is_err());
+/// ```
+///
+/// By default, `from_str` only compares the input in full to the values and aliases of the
+/// same length and first byte. With the `phf` feature, `#[phf]` makes it look the input up in
+/// a perfect hash table built at compile time instead, which keeps enums with thousands of
+/// variants fast to parse. It has no effect together with `#[case_insensitive]`.
+#[cfg_attr(feature = "phf", doc = "```")]
+#[cfg_attr(not(feature = "phf"), doc = "```ignore")]
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     #[phf]
+///     Airport,
+///     (Berlin, "BER"),
+///     (Lisbon, "LIS"),
+///     (Tokyo, "HND"),
+/// }
+///
+/// assert_eq!(Airport::Lisbon, "LIS".parse().unwrap());
+/// ```
+///
+/// `#[binary_search]` instead looks the input up in a table of the values and aliases that is
+/// sorted at compile time, which takes less code for enums with hundreds of values.
+/// Like `#[phf]`, it has no effect together with `#[case_insensitive]`, and cannot be used
+/// with a catch-all.
+/// ```
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     #[binary_search]
+///     Airport,
+///     (Berlin, "BER"),
+///     (Lisbon, "LIS", aliases = ["LPPT"]),
+///     (Tokyo, "HND"),
+/// }
+///
+/// assert_eq!(Airport::Lisbon, "LPPT".parse().unwrap());
+/// assert!("AMS".parse::<Airport>().is_err());
+/// ```
+///
+/// With the `std` feature, `#[hash_map]` looks the input up in a `HashMap` of the values and
+/// aliases, built on first use. For enums with thousands of values this is faster than the
+/// default lookup and cheaper to compile than `#[phf]`. It has no effect together with
+/// `#[case_insensitive]` either.
+#[cfg_attr(feature = "std", doc = "```")]
+#[cfg_attr(not(feature = "std"), doc = "```ignore")]
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     #[hash_map]
+///     Locale,
+///     (EnUs, "en-US"),
+///     (DeDe, "de-DE", aliases = ["de"]),
+/// }
+///
+/// assert_eq!(Locale::DeDe, "de".parse().unwrap());
+/// assert!("fr-FR".parse::<Locale>().is_err());
+/// ```
+///
+/// With the `unicode-normalization` feature, `#[nfc]` compares the NFC forms of the input and
+/// the values, so composed and decomposed accents match alike. It can be combined with
+/// `#[case_insensitive]` and `#[trim]`, and takes precedence over `#[phf]`.
+#[cfg_attr(feature = "unicode-normalization", doc = "```")]
+#[cfg_attr(not(feature = "unicode-normalization"), doc = "```ignore")]
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     #[nfc]
+///     Dish,
+///     (Creme, "cr\u{e8}me"),
+///     (Pate, "pa\u{302}te\u{301}"),
+/// }
+///
+/// assert_eq!(Dish::Creme, "cre\u{300}me".parse().unwrap());
+/// assert_eq!(Dish::Pate, "p\u{e2}t\u{e9}".parse().unwrap());
+/// ```
+///
+/// The crate and the generated code only need `core`. Converting into a `String` or a
+/// `Cow<'static, str>`, which borrows the value, and keeping the rejected input in
+/// [`ParseError`] need the `alloc` feature, which the default `std`
+/// feature enables. With `std`, the enum also implements `TryFrom<&OsStr>`, so command line
+/// arguments and environment variables can be parsed without converting them to `String`.
+#[cfg_attr(feature = "std", doc = "```")]
+#[cfg_attr(not(feature = "std"), doc = "```ignore")]
+/// use std::ffi::OsStr;
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     Shell,
+///     (Bash, "bash"),
+///     (Zsh, "zsh"),
+/// }
+///
+/// assert_eq!(Ok(Shell::Zsh), Shell::try_from(OsStr::new("zsh")));
+/// ```
+///
+/// `from_env` reads an environment variable, trims it and parses it. Its [`EnvError`] tells
+/// a variable that is not set from one holding an invalid value.
+#[cfg_attr(feature = "std", doc = "```")]
+#[cfg_attr(not(feature = "std"), doc = "```ignore")]
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     Shell,
+///     (Bash, "bash"),
+///     (Zsh, "zsh"),
+/// }
+///
+/// std::env::set_var("DOC_SHELL", " zsh\n");
+/// assert_eq!(Ok(Shell::Zsh), Shell::from_env("DOC_SHELL"));
+///
+/// let err = Shell::from_env("DOC_SHELL_UNSET").unwrap_err();
+/// assert!(err.is_not_present());
+/// assert_eq!("environment variable DOC_SHELL_UNSET is not set", err.to_string());
+/// ```
+///
+/// With `alloc`, `parse_list` parses a list of values separated by a delimiter, as found in
+/// environment variables and HTTP headers, and `join` writes one. A [`ListError`] tells which
+/// element failed to parse.
+#[cfg_attr(feature = "alloc", doc = "```")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     Fruit,
+///     (Apple, "🍎"),
+///     (Pineapple, "🍍"),
+///     (Strawberry, "🍓"),
+/// }
+///
+/// let list = Fruit::parse_list("🍎, 🍓", ",").unwrap();
+/// assert_eq!(vec![Fruit::Apple, Fruit::Strawberry], list);
+/// assert_eq!("🍎;🍓", Fruit::join(&list, ";"));
+///
+/// let err = Fruit::parse_list("🍎,🍌", ",").unwrap_err();
+/// assert_eq!(1, err.index());
+/// assert_eq!(5, err.offset());
+/// ```
+///
+/// With the `serde` feature enabled, the enum also implements `Serialize` and `Deserialize`
+/// using its string value.
+#[cfg_attr(feature = "serde", doc = "```")]
+#[cfg_attr(not(feature = "serde"), doc = "```ignore")]
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     Fruit,
+///     (Apple, "🍎"),
+///     (Pineapple, "🍍"),
+/// }
+///
+/// assert_eq!("\"🍎\"", serde_json::to_string(&Fruit::Apple).unwrap());
+/// assert_eq!(Fruit::Pineapple, serde_json::from_str("\"🍍\"").unwrap());
+/// ```
+///
+/// `#[serde(names)]` lets `Deserialize` accept the names of the variants as well, checked after
+/// the values and aliases, which helps reading data written before the values were introduced.
+#[cfg_attr(feature = "serde", doc = "```")]
+#[cfg_attr(not(feature = "serde"), doc = "```ignore")]
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     #[serde(names)]
+///     Fruit,
+///     (Apple, "🍎"),
+///     (Pineapple, "🍍"),
+/// }
+///
+/// assert_eq!(Fruit::Apple, serde_json::from_str("\"Apple\"").unwrap());
+/// assert_eq!(Fruit::Apple, serde_json::from_str("\"🍎\"").unwrap());
+/// assert_eq!("\"🍎\"", serde_json::to_string(&Fruit::Apple).unwrap());
+/// assert!("Apple".parse::<Fruit>().is_err());
+/// ```
+///
+/// `#[serde(other = Unknown)]` makes `Deserialize` return the `Unknown` variant for strings
+/// that match no variant, instead of failing, so data written by newer versions can be read.
+#[cfg_attr(feature = "serde", doc = "```")]
+#[cfg_attr(not(feature = "serde"), doc = "```ignore")]
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     #[serde(names, other = Unknown)]
+///     Event,
+///     (Click, "click"),
+///     (Unknown, "unknown"),
+/// }
+///
+/// assert_eq!(Event::Click, serde_json::from_str("\"Click\"").unwrap());
+/// assert_eq!(Event::Unknown, serde_json::from_str("\"scroll\"").unwrap());
+/// assert!("scroll".parse::<Event>().is_err());
+/// ```
+///
+/// `#[serde(module = name)]` also generates a module with `serialize` and `deserialize`
+/// functions, so fields can be (de)serialized through the string value with
+/// `#[serde(with = "name")]`, even when the enum itself keeps other impls. The enum has to be
+/// declared in a module rather than in a function.
+#[cfg_attr(feature = "serde", doc = "```")]
+#[cfg_attr(not(feature = "serde"), doc = "```ignore")]
+/// use enum_str::enum_str;
+/// use serde::Serialize;
+///
+/// enum_str! {
+///     #[serde(module = fruit_str)]
+///     #[skip(Serialize)]
+///     #[derive(Debug, Clone, PartialEq, Serialize)]
+///     Fruit,
+///     (Apple, "🍎"),
+///     (Pineapple, "🍍"),
+/// }
+///
+/// #[derive(Serialize)]
+/// struct Basket {
+///     #[serde(with = "fruit_str")]
+///     fruit: Fruit,
+///     name_only: Fruit,
+/// }
+///
+/// # fn main() {
+/// let basket = Basket { fruit: Fruit::Apple, name_only: Fruit::Apple };
+/// assert_eq!(r#"{"fruit":"🍎","name_only":"Apple"}"#, serde_json::to_string(&basket).unwrap());
+/// # }
+/// ```
+///
+/// `mapping` returns the name and value of every variant as a [`Mapping`], which serializes
+/// as a map from names to values, e.g. to list the accepted values in an introspection
+/// endpoint.
+#[cfg_attr(feature = "serde", doc = "```")]
+#[cfg_attr(not(feature = "serde"), doc = "```ignore")]
+/// use enum_str::enum_str;
+///
+/// enum_str! {
+///     Fruit,
+///     (Apple, "🍎"),
+///     (Pineapple, "🍍"),
+/// }
+///
+/// assert_eq!(Some("🍍"), Fruit::mapping().get("Pineapple"));
+/// assert_eq!(
+///     r#"{"Apple":"🍎","Pineapple":"🍍"}"#,
+///     serde_json::to_string(&Fruit::mapping()).unwrap()
+/// );
+/// ```
+///
+/// Other features implement traits from further crates:
+/// - `schemars`: `JsonSchema`, describing the enum as a string restricted to its values and
+///   aliases.
+/// - `clap`: `clap::ValueEnum`, so the enum can be used as a command line argument with its
+///   values (and aliases) as the possible values. It requires the enum to implement `Clone`,
+///   which the default derive list does. Clap matches the possible values itself, exactly and
+///   without `#[trim]`: a `#[case_insensitive]` enum needs `.ignore_case(true)` on the `Arg`.
+///   An enum with a `_ =>` catch-all gets no `ValueEnum`, so `value_parser!` parses it with
+///   `FromStr` and accepts any string.
+/// - `strum`: `IntoEnumIterator`, `EnumCount` and `VariantNames`, which also need `Clone`.
+/// - `sqlx`: `Type`, `Encode` and `Decode` for every database whose text type `str` maps to,
+///   so the enum can be bound as a parameter and read from rows. Values that match no variant
+///   fail to decode with the [`ParseError`].
+/// - `diesel`: `AsExpression`, `ToSql` and `FromSql` for the `Text` SQL type and `Queryable`,
+///   so the enum can be used in models backed by text columns. `ToSql` requires `Debug`.
+/// - `rusqlite`: `ToSql` and `FromSql`, storing the string value. Reading a value that is not
+///   text fails with `FromSqlError::InvalidType`, and one that matches no variant with
+///   `FromSqlError::Other` wrapping the [`ParseError`].
+/// - `postgres`: `ToSql` and `FromSql` from `postgres-types`, accepting the same column types
+///   as `&str`, so the enum can be passed to `tokio-postgres` and `postgres` queries directly.
+///   `ToSql` requires `Debug`.
+/// - `pyo3`: `IntoPyObject` and `FromPyObject`, converting to and from Python strings. A
+///   string that matches no variant raises `ValueError` with the message of the
+///   [`ParseError`], which lists the accepted values.
+/// - `wasm`: `From<Enum>` for `JsValue` and `TryFrom<JsValue>`, so the enum can be passed to
+///   and from JS as a string in functions exported with `#[wasm_bindgen]`. Conversion fails
+///   with a JS `Error` when the value is not a string or matches no variant.
+/// - `arbitrary`: `Arbitrary`, picking one of the variants for fuzz targets.
+/// - `proptest`: proptest's `Arbitrary`, so `any::<Enum>()` yields every variant. Proptest
+///   values have to implement `Debug`.
+/// - `quickcheck`: quickcheck's `Arbitrary`, picking one of the variants and shrinking toward
+///   the first declared one. It relies on the `Clone` derive.
+/// - `rand`: `Distribution<Enum>` for `StandardUniform`, so `rng.random::<Enum>()` samples a
+///   variant uniformly.
+/// - `nom`: a `parse(input: &str) -> IResult<&str, Enum>` function matching the longest value
+///   or alias at the start of the input, to be used as a `nom` parser. If the enum has a
+///   `parse` of its own, `#[rename(parse = ...)]` gives the generated one another name.
+/// - `winnow`: a `parse_next` function doing the same for `winnow`, which implements
+///   `winnow::Parser` for any error type of the grammar.
+#[macro_export]
+macro_rules! enum_str {
+    // Sort the attributes in front of the name into derives, options understood by this
+    // macro and everything else, so the default derive list is only used when none was given.
+    (@attrs [$($derive:tt)*] [$($attr:tt)*] [$($opt:tt)*] #[derive $list:tt] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs [$($derive)* #[derive $list]] [$($attr)*] [$($opt)*] $($rest)* }
+    };
+    (@attrs [$($derive:tt)*] [$($attr:tt)*] [$($opt:tt)*] #[case_insensitive] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs [$($derive)*] [$($attr)*] [$($opt)* {case_insensitive}] $($rest)* }
+    };
+    (@attrs [$($derive:tt)*] [$($attr:tt)*] [$($opt:tt)*] #[eq_str] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs [$($derive)*] [$($attr)*] [$($opt)* {eq_str}] $($rest)* }
+    };
+    (@attrs [$($derive:tt)*] [$($attr:tt)*] [$($opt:tt)*] #[borrow_str] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs [$($derive)*] [$($attr)*] [$($opt)* {borrow_str}] $($rest)* }
+    };
+    (@attrs [$($derive:tt)*] [$($attr:tt)*] [$($opt:tt)*] #[debug_str] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs [$($derive)*] [$($attr)*] [$($opt)* {debug_str}] $($rest)* }
+    };
+    (@attrs [$($derive:tt)*] [$($attr:tt)*] [$($opt:tt)*] #[trim] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs [$($derive)*] [$($attr)*] [$($opt)* {trim}] $($rest)* }
+    };
+    (@attrs [$($derive:tt)*] [$($attr:tt)*] [$($opt:tt)*] #[trim(collapse)] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs [$($derive)*] [$($attr)*] [$($opt)* {trim} {collapse}] $($rest)* }
+    };
+    (@attrs [$($derive:tt)*] [$($attr:tt)*] [$($opt:tt)*] #[binary_search] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs [$($derive)*] [$($attr)*] [$($opt)* {binary_search}] $($rest)* }
+    };
+    (@attrs [$($derive:tt)*] [$($attr:tt)*] [$($opt:tt)*] #[hash_map] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs [$($derive)*] [$($attr)*] [$($opt)* {hash_map}] $($rest)* }
+    };
+    (@attrs [$($derive:tt)*] [$($attr:tt)*] [$($opt:tt)*] #[phf] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs [$($derive)*] [$($attr)*] [$($opt)* {phf}] $($rest)* }
+    };
+    (@attrs [$($derive:tt)*] [$($attr:tt)*] [$($opt:tt)*] #[map($map:ident)] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs [$($derive)*] [$($attr)*] [$($opt)* {map($map)}] $($rest)* }
+    };
+    (@attrs [$($derive:tt)*] [$($attr:tt)*] [$($opt:tt)*] #[set($set:ident $(,)?)] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs [$($derive)*] [$($attr)*] [$($opt)* {set($set, u64)}] $($rest)* }
+    };
+    (@attrs [$($derive:tt)*] [$($attr:tt)*] [$($opt:tt)*] #[set($set:ident, $int:ident $(,)?)] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs [$($derive)*] [$($attr)*] [$($opt)* {set($set, $int)}] $($rest)* }
+    };
+    (@attrs [$($derive:tt)*] [$($attr:tt)*] [$($opt:tt)*] #[locale($locale:ident $(,)?)] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs [$($derive)*] [$($attr)*] [$($opt)* {locale($locale)}] $($rest)* }
+    };
+    (@attrs [$($derive:tt)*] [$($attr:tt)*] [$($opt:tt)*] #[serde(names $(,)?)] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs [$($derive)*] [$($attr)*] [$($opt)* {serde(names)}] $($rest)* }
+    };
+    (@attrs [$($derive:tt)*] [$($attr:tt)*] [$($opt:tt)*] #[serde(other = $other:ident $(,)?)] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs [$($derive)*] [$($attr)*] [$($opt)* {serde(other = $other)}] $($rest)* }
+    };
+    (@attrs [$($derive:tt)*] [$($attr:tt)*] [$($opt:tt)*] #[serde(module = $module:ident $(,)?)] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs [$($derive)*] [$($attr)*] [$($opt)* {serde(module = $module)}] $($rest)* }
+    };
+    (@attrs $derive:tt $attr:tt $opt:tt #[serde($arg:ident $(= $value:ident)?, $($args:tt)+)] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs $derive $attr $opt #[serde($arg $(= $value)?)] #[serde($($args)+)] $($rest)* }
+    };
+    (@attrs $derive:tt $attr:tt $opt:tt #[serde($($args:tt)*)] $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "unsupported serde option `", ::core::stringify!($($args)*), "`, expected `names`, `other = Variant` or `module = name`"
+        ));
+    };
+    (@attrs [$($derive:tt)*] [$($attr:tt)*] [$($opt:tt)*] #[nfc] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs [$($derive)*] [$($attr)*] [$($opt)* {nfc}] $($rest)* }
+    };
+    (@attrs [$($derive:tt)*] [$($attr:tt)*] [$($opt:tt)*] #[__enum_str_error_enum] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs [$($derive)*] [$($attr)*] [$($opt)* {error_enum}] $($rest)* }
+    };
+    (@attrs [$($derive:tt)*] [$($attr:tt)*] [$($opt:tt)*] #[__enum_str_impl_only] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs [$($derive)*] [$($attr)*] [$($opt)* {impl_only}] $($rest)* }
+    };
+    (@attrs [$($derive:tt)*] [$($attr:tt)*] [$($opt:tt)*] #[char] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs [$($derive)*] [$($attr)*] [$($opt)* {char}] $($rest)* }
+    };
+    (@attrs [$($derive:tt)*] [$($attr:tt)*] [$($opt:tt)*] #[suggest] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs [$($derive)*] [$($attr)*] [$($opt)* {suggest}] $($rest)* }
+    };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[error($err:ty $(, with = $with:path)? $(,)?)] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs $derive $attr [$($opt)* {error($err $(, with = $with)?)}] $($rest)* }
+    };
+    (@attrs $derive:tt $attr:tt $opt:tt #[vis($item:ident = $to:vis, $($more:tt)+)] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs $derive $attr $opt #[vis($item = $to)] #[vis($($more)+)] $($rest)* }
+    };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(VARIANTS = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(VARIANTS = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(VALUES = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(VALUES = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(TABLE = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(TABLE = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(COUNT = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(COUNT = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(MAX_STR_LEN = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(MAX_STR_LEN = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(MIN_STR_LEN = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(MIN_STR_LEN = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(PATTERN = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(PATTERN = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(as_str = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(as_str = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(as_bytes = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(as_bytes = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(as_char = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(as_char = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(from_char = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(from_char = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(as_short_str = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(as_short_str = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(as_long_str = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(as_long_str = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(name = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(name = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(from_name = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(from_name = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(from_str_ignore_ascii_case = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(from_str_ignore_ascii_case = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(is_valid = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(is_valid = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(from_str_unchecked = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(from_str_unchecked = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(strip_prefix = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(strip_prefix = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(match_longest_at = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(match_longest_at = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(parse = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(parse = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(find_all = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(find_all = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(code = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(code = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(from_code = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(from_code = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(from_env = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(from_env = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(parse_list = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(parse_list = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(join = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(join = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(mapping = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(mapping = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(index = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(index = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(from_index = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(from_index = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(next = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(next = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(prev = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(prev = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(next_wrapping = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(next_wrapping = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(prev_wrapping = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(prev_wrapping = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(cmp_str = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(cmp_str = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(sorted_variants = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(sorted_variants = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(as_str_locale = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(as_str_locale = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(from_str_any_locale = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(from_str_any_locale = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[vis(iter = $to:vis $(,)?)] $($rest:tt)*) => { $crate::enum_str! { @attrs $derive $attr [$($opt)* {vis(iter = $to)}] $($rest)* } };
+    (@attrs $derive:tt $attr:tt $opt:tt #[vis($($vis:tt)*)] $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "cannot set the visibility of `", ::core::stringify!($($vis)*), "`, expected `item = visibility` for one ",
+            "of VARIANTS, VALUES, TABLE, COUNT, MAX_STR_LEN, MIN_STR_LEN, PATTERN, as_str, as_short_str, ",
+            "as_long_str, as_bytes, as_char, from_char, name, from_name, from_str_ignore_ascii_case, is_valid, ",
+            "from_str_unchecked, strip_prefix, match_longest_at, parse, find_all, code, from_code, ",
+            "from_env, parse_list, join, mapping, index, from_index, next, prev, next_wrapping, prev_wrapping, ",
+            "cmp_str, sorted_variants, as_str_locale, from_str_any_locale or iter"
+        ));
+    };
+    (@attrs $derive:tt $attr:tt $opt:tt #[rename($item:ident = $to:ident, $($more:tt)+)] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs $derive $attr $opt #[rename($item = $to)] #[rename($($more)+)] $($rest)* }
+    };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[rename(as_str = $to:ident $(,)?)] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs $derive $attr [$($opt)* {rename(as_str = $to)}] $($rest)* }
+    };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[rename(parse = $to:ident $(,)?)] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs $derive $attr [$($opt)* {rename(parse = $to)}] $($rest)* }
+    };
+    (@attrs $derive:tt $attr:tt $opt:tt #[rename($($rename:tt)*)] $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "cannot rename `", ::core::stringify!($($rename)*),
+            "`, expected `rename(as_str = new_name)` or `rename(parse = new_name)`"
+        ));
+    };
+    (@attrs $derive:tt $attr:tt $opt:tt #[skip($skip:ident, $($more:ident),+ $(,)?)] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs $derive $attr $opt #[skip($skip)] #[skip($($more),+)] $($rest)* }
+    };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[skip(Display $(,)?)] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs $derive $attr [$($opt)* {skip(Display)}] $($rest)* }
+    };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[skip(FromStr $(,)?)] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs $derive $attr [$($opt)* {skip(FromStr)}] $($rest)* }
+    };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[skip(AsRef $(,)?)] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs $derive $attr [$($opt)* {skip(AsRef)}] $($rest)* }
+    };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[skip(From $(,)?)] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs $derive $attr [$($opt)* {skip(From)}] $($rest)* }
+    };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[skip(TryFrom $(,)?)] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs $derive $attr [$($opt)* {skip(TryFrom)}] $($rest)* }
+    };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[skip(Serialize $(,)?)] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs $derive $attr [$($opt)* {skip(Serialize)}] $($rest)* }
+    };
+    (@attrs $derive:tt $attr:tt [$($opt:tt)*] #[skip(Deserialize $(,)?)] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs $derive $attr [$($opt)* {skip(Deserialize)}] $($rest)* }
+    };
+    (@attrs $derive:tt $attr:tt $opt:tt #[skip($($skip:tt)*)] $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "cannot skip `", ::core::stringify!($($skip)*),
+            "`, expected Display, FromStr, AsRef, From, TryFrom, Serialize or Deserialize"
+        ));
+    };
+    (@attrs [$($derive:tt)*] [$($attr:tt)*] [$($opt:tt)*] #[repr($repr:ident)] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs [$($derive)*] [$($attr)* #[repr($repr)]] [$($opt)* {repr($repr)}] $($rest)* }
+    };
+    (@attrs [$($derive:tt)*] [$($attr:tt)*] [$($opt:tt)*] #[$meta:meta] $($rest:tt)*) => {
+        $crate::enum_str! { @attrs [$($derive)*] [$($attr)* #[$meta]] [$($opt)*] $($rest)* }
+    };
+    (@attrs [] [$($attr:tt)*] [$($opt:tt)*] $($rest:tt)*) => {
+        $crate::__enum_str_if! { debug_str, [$($opt)*], {
+            $crate::enum_str! { @enum [#[derive(Clone, PartialEq)] $($attr)*] [$($opt)*] $($rest)* }
+        }, {
+            $crate::enum_str! { @enum [#[derive(Debug, Clone, PartialEq)] $($attr)*] [$($opt)*] $($rest)* }
+        } }
+    };
+    (@attrs [$($derive:tt)+] [$($attr:tt)*] [$($opt:tt)*] $($rest:tt)*) => {
+        $crate::enum_str! { @enum [$($derive)+ $($attr)*] [$($opt)*] $($rest)* }
+    };
+
+    (
+        @enum [$($attr:tt)*] [$($opt:tt)*] $vis:vis $name:ident,
+        $($(#[$($vattr:tt)*])* ($key:ident $(= $discr:expr)?, $value:expr $(, $flag:tt $(= [$($alias:expr),* $(,)?])? $(($($arg:tt)*))?)*),)*
+        $(_ => $other:ident $(,)?)?
+    ) => {
+        $crate::__enum_str_if! { impl_only, [$($opt)*], {}, {
+            $($attr)*
+            #[doc = ""]
+            #[doc = "| Variant | String value |"]
+            #[doc = "|---|---|"]
+            $(#[doc = ::core::concat!("| `", ::core::stringify!($key), "` | `", ::core::stringify!($value), "` |")])*
+            $(#[doc = ::core::concat!("| `", ::core::stringify!($other), "(_)` | any other string |")])?
+            $vis enum $name
+            {
+                $(
+                    $(#[$($vattr)*])*
+                    #[doc = ""]
+                    #[doc = ::core::concat!("String value: `", ::core::stringify!($value), "`")]
+                    $key $(= $discr)?,
+                )*
+                $(
+                    #[doc = "Any string that matches no other variant."]
+                    $other($crate::__private::String),
+                )?
+            }
+        } }
+
+        $crate::enum_str! {
+            @cfg {[$($opt)*] $vis $name, [$($other)?]} []
+            $({ $(#[$($vattr)*])* ($key, $value $(, $flag $(= [$($alias),*])? $(($($arg)*))?)*) })*
+        }
+    };
+
+    // Reduce the attributes of every variant to a single `#[cfg(all(...))]`, which is all the
+    // generated match arms can carry. Variants with nothing but doc attributes are taken in
+    // runs, the others one at a time.
+    (@cfg $head:tt [$($done:tt)*] $({ $(#[doc $($_doc:tt)*])* ($($variant:tt)*) })*) => {
+        $crate::enum_str! { @impl $head $($done)* $(#[cfg(all())] ($($variant)*),)* }
+    };
+    (
+        @cfg $head:tt [$($done:tt)*] $({ $(#[doc $($_doc:tt)*])* ($($variant:tt)*) })*
+        { #[cfg $pred:tt] $($next:tt)* } $($rest:tt)*
+    ) => {
+        $crate::enum_str! {
+            @cfg_attrs $head [$($done)* $(#[cfg(all())] ($($variant)*),)*] [$pred] $($next)*; $($rest)*
+        }
+    };
+    (@cfg $head:tt [$($done:tt)*] { $($next:tt)* } $($rest:tt)*) => {
+        $crate::enum_str! { @cfg_attrs $head [$($done)*] [] $($next)*; $($rest)* }
+    };
+    (@cfg_attrs $head:tt $done:tt [$($pred:tt)*] #[cfg $next:tt] $($rest:tt)*) => {
+        $crate::enum_str! { @cfg_attrs $head $done [$($pred)* $next] $($rest)* }
+    };
+    (@cfg_attrs $head:tt $done:tt $pred:tt #[$($_attr:tt)*] $($rest:tt)*) => {
+        $crate::enum_str! { @cfg_attrs $head $done $pred $($rest)* }
+    };
+    (@cfg_attrs $head:tt [$($done:tt)*] [$(($($pred:tt)*))*] ($($variant:tt)*); $($rest:tt)*) => {
+        $crate::enum_str! { @cfg $head [$($done)* #[cfg(all($($($pred)*),*))] ($($variant)*),] $($rest)* }
+    };
+
+    (@impl {[$($opt:tt)*] $vis:vis $name:ident, [$($other:ident)?]} $($variant:tt)*) => {
+        $crate::__enum_str_if! { char, [$($opt)*], {
+            $crate::__enum_str_char! { [$($opt)*] $vis $name, [$($other)?], $($variant)* }
+        }, {
+            $crate::__enum_str_impl! {
+                [$($opt)*] $vis $name,
+                $($variant)*
+                $(_ => $other,)?
+            }
+        } }
+    };
+
+    // `impl Name { ... }` generates everything but the enum itself, which is declared elsewhere.
+    ($(#[$($attr:tt)*])* $vis:vis impl $name:ident { $($variants:tt)* }) => {
+        $crate::enum_str! { #[__enum_str_impl_only] $(#[$($attr)*])* $vis $name, $($variants)* }
+    };
+
+    // Several enums in one block share the attributes and the visibility in front of it.
+    (
+        $(#[$($attr:tt)*])*
+        $vis:vis enum { $($enums:tt)* }
+    ) => {
+        $crate::enum_str! { @block [$(#[$($attr)*])*] $vis, $($enums)* }
+    };
+    (
+        @block $shared:tt $vis:vis,
+        $($(#[$($attr:tt)*])* $name:ident { $($variants:tt)* } $(,)?)*
+    ) => {
+        $(
+            $crate::enum_str! { @shared $shared $(#[$($attr)*])* $vis $name, $($variants)* }
+        )*
+    };
+    (@shared [$($shared:tt)*] $($rest:tt)*) => {
+        $crate::enum_str! { $($shared)* $($rest)* }
+    };
+
+    (
+        $(#[$($attr:tt)*])*
+        $vis:vis $name:ident,
+        $($(#[$($vattr:tt)*])* ($key:ident $(= $discr:expr)?, $value:expr $(, $flag:tt $(= [$($alias:expr),* $(,)?])? $(($($arg:tt)*))?)* $(,)?)),* $(,)?
+        $(_ => $other:ident $(,)?)?
+    ) => {
+        $crate::enum_str! {
+            @attrs [] [] []
+            $(#[$($attr)*])*
+            $vis $name,
+            $($(#[$($vattr)*])* ($key $(= $discr)?, $value $(, $flag $(= [$($alias),*])? $(($($arg)*))?)*),)*
+            $(_ => $other,)?
+        }
+    };
+
+    // `Key = "value"` and `Key => "value"` arms are shorthands for `(Key, "value")`.
+    (
+        $(#[$($attr:tt)*])*
+        $vis:vis $name:ident,
+        $($(#[$($vattr:tt)*])* $key:ident = $value:expr),*
+        $(, _ => $other:ident)? $(,)?
+    ) => {
+        $crate::enum_str! {
+            $(#[$($attr)*])*
+            $vis $name,
+            $($(#[$($vattr)*])* ($key, $value),)*
+            $(_ => $other,)?
+        }
+    };
+    (
+        $(#[$($attr:tt)*])*
+        $vis:vis $name:ident,
+        $($(#[$($vattr:tt)*])* $key:ident => $value:expr),*
+        $(, _ => $other:ident)? $(,)?
+    ) => {
+        $crate::enum_str! {
+            $(#[$($attr)*])*
+            $vis $name,
+            $($(#[$($vattr)*])* ($key, $value),)*
+            $(_ => $other,)?
+        }
+    };
+    // `mod name,` in front wraps everything in a module, which sees the items of its parent.
+    ($(#[$mattr:meta])* $mvis:vis mod $module:ident, $($rest:tt)*) => {
+        $(#[$mattr])*
+        $mvis mod $module {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $crate::enum_str! { $($rest)* }
+        }
+    };
+    // Input matching none of the forms above is checked for common mistakes, to report them
+    // instead of `no rules expected this token`.
+    (@diagnose $(#[$($attr:tt)*])* $vis:vis $name:ident, $($rest:tt)*) => {
+        $crate::enum_str! { @diagnose_variants $name, [], $($rest)* }
+    };
+    (@diagnose $(#[$($attr:tt)*])* $vis:vis $name:ident $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!("expected a `,` after the enum name `", ::core::stringify!($name), "`"));
+    };
+    (@diagnose $($rest:tt)*) => {
+        ::core::compile_error!("expected the name of the enum and a `,`, optionally after attributes and a visibility");
+    };
+
+    (
+        @diagnose_variants $name:ident, [short],
+        $(#[$($vattr:tt)*])* ($key:ident $(= $discr:expr)?, $value:expr $(, $flag:tt $(= [$($alias:expr),* $(,)?])? $(($($arg:tt)*))?)* $(,)?)
+        $($rest:tt)*
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "`", ::core::stringify!($name), "::", ::core::stringify!($key),
+            "` is written `(Key, value)`, which cannot be mixed with the `Key = value` and `Key => value` shorthands"
+        ));
+    };
+    (
+        @diagnose_variants $name:ident, $_seen:tt,
+        $(#[$($vattr:tt)*])* ($key:ident $(= $discr:expr)?, $value:expr $(, $flag:tt $(= [$($alias:expr),* $(,)?])? $(($($arg:tt)*))?)* $(,)?)
+        $(, $($rest:tt)*)?
+    ) => {
+        $crate::enum_str! { @diagnose_variants $name, [tuple], $($($rest)*)? }
+    };
+    (@diagnose_variants $name:ident, $_seen:tt, $(#[$($vattr:tt)*])* ($key:ident = $value:literal $(, $($flags:tt)*)?) $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "expected `(", ::core::stringify!($key), ", ", ::core::stringify!($value), ")`, with a `,` between the name and the value of `",
+            ::core::stringify!($name), "::", ::core::stringify!($key), "`"
+        ));
+    };
+    (@diagnose_variants $name:ident, $_seen:tt, $(#[$($vattr:tt)*])* ($key:ident $(= $discr:expr)?) $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "`", ::core::stringify!($name), "::", ::core::stringify!($key), "` has no value, expected `(",
+            ::core::stringify!($key), ", \"value\")`"
+        ));
+    };
+    (@diagnose_variants $name:ident, $_seen:tt, $(#[$($vattr:tt)*])* ($key:ident $($variant:tt)*) $(, $($rest:tt)*)?) => {
+        ::core::compile_error!(::core::concat!(
+            "cannot parse `", ::core::stringify!($name), "::", ::core::stringify!($key), "`, expected `(",
+            ::core::stringify!($key), ", \"value\")` followed by a code, `aliases = [...]`, `short = [...]`, ",
+            "`long = [...]`, `locale(...)` or `default`"
+        ));
+    };
+    (@diagnose_variants $name:ident, $_seen:tt, $(#[$($vattr:tt)*])* ($key:ident $($variant:tt)*) $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "expected a `,` after `", ::core::stringify!($name), "::", ::core::stringify!($key), "`"
+        ));
+    };
+    (@diagnose_variants $name:ident, [tuple], $(#[$($vattr:tt)*])* $key:ident = $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "`", ::core::stringify!($name), "::", ::core::stringify!($key),
+            "` uses a shorthand, which cannot be mixed with `(Key, value)` variants"
+        ));
+    };
+    (@diagnose_variants $name:ident, [tuple], $(#[$($vattr:tt)*])* $key:ident => $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "`", ::core::stringify!($name), "::", ::core::stringify!($key),
+            "` uses a shorthand, which cannot be mixed with `(Key, value)` variants"
+        ));
+    };
+    (@diagnose_variants $name:ident, $_seen:tt, $(#[$($vattr:tt)*])* $key:ident = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::enum_str! { @diagnose_variants $name, [short], $($($rest)*)? }
+    };
+    (@diagnose_variants $name:ident, $_seen:tt, $(#[$($vattr:tt)*])* $key:ident => $value:expr $(, $($rest:tt)*)?) => {
+        $crate::enum_str! { @diagnose_variants $name, [short], $($($rest)*)? }
+    };
+    (@diagnose_variants $name:ident, $_seen:tt, _ => $other:ident, $($rest:tt)+) => {
+        ::core::compile_error!(::core::concat!("`_ => ", ::core::stringify!($other), "` has to be the last entry"));
+    };
+    (@diagnose_variants $name:ident, $_seen:tt, $($rest:tt)+) => {
+        ::core::compile_error!(::core::concat!(
+            "unexpected `", ::core::stringify!($($rest)+), "` in `", ::core::stringify!($name),
+            "`, expected variants like `(Key, \"value\")`"
+        ));
+    };
+    (@diagnose_variants $name:ident, $_seen:tt, $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "cannot parse `", ::core::stringify!($name), "`, expected only `(Key, \"value\")` variants, or only ",
+            "`Key = \"value\"` or `Key => \"value\"` shorthands, optionally followed by `_ => Other`"
+        ));
+    };
+
+    ($($input:tt)*) => {
+        $crate::enum_str! { @diagnose $($input)* }
+    };
+}
+
+/// Generates the methods and trait impls for an enum declared by `enum_str!` or deriving
+/// `EnumStr`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_impl {
+    (
+        [$($opt:tt)*] $vis:vis $name:ident,
+        $(#[cfg $cfg:tt] ($key:ident, $value:expr $(, $flag:tt $(= [$($alias:expr),* $(,)?])? $(($($arg:tt)*))?)*),)*
+        $(_ => $other:ident,)?
+    ) => {
+        // Values of the wrong type, such as a `char` in an enum that is not `#[char]`, are
+        // reported once with a hint before the errors of the code that uses them.
+        const _: () = {
+            $(
+                #[cfg $cfg]
+                $crate::__private::check_value($value);
+                $($($(#[cfg $cfg] $crate::__private::check_value($alias);)*)*)*
+            )*
+        };
+
+        // Two variants parsed from the same string would leave one of them unreachable.
+        const _: () = {
+            const STRS: &[&str] = &[$(#[cfg $cfg] $value, $($($(#[cfg $cfg] $alias,)*)*)*)*];
+            $(
+                #[cfg $cfg]
+                ::core::assert!(
+                    $crate::__private::count_str(STRS, $value) == 1,
+                    ::core::concat!("the value of `", ::core::stringify!($name), "::", ::core::stringify!($key), "` is used more than once"),
+                );
+                $($($(
+                    #[cfg $cfg]
+                    ::core::assert!(
+                        $crate::__private::count_str(STRS, $alias) == 1,
+                        ::core::concat!("an alias of `", ::core::stringify!($name), "::", ::core::stringify!($key), "` is used more than once"),
+                    );
+                )*)?)*
+            )*
+        };
+
+        #[allow(dead_code)]
+        impl $name {
+            $crate::__enum_str_item! { VARIANTS, [$($opt)*], $vis,
+                { /// Every variant in declaration order.
+                } { const } { : &'static [$name] = &[$(#[cfg $cfg] $name::$key),*]; }
+            }
+
+            $crate::__enum_str_item! { VALUES, [$($opt)*], $vis,
+                { /// The string value of every variant, in the same order as `VARIANTS`.
+                } { const } { : &'static [&'static str] = &[$(#[cfg $cfg] $value),*]; }
+            }
+
+            $crate::__enum_str_item! { TABLE, [$($opt)*], $vis,
+                { /// Every variant paired with its string value, in declaration order.
+                } { const } { : &'static [($name, &'static str)] = &[$(#[cfg $cfg] ($name::$key, $value)),*]; }
+            }
+
+            $crate::__enum_str_item! { COUNT, [$($opt)*], $vis,
+                { /// Number of variants.
+                } { const } { : usize = $name::VARIANTS.len(); }
+            }
+
+            $crate::__enum_str_item! { MAX_STR_LEN, [$($opt)*], $vis,
+                { /// Length in bytes of the longest value.
+                } { const } { : usize = $crate::__private::max_len($name::VALUES); }
+            }
+
+            $crate::__enum_str_item! { MIN_STR_LEN, [$($opt)*], $vis,
+                { /// Length in bytes of the shortest value.
+                } { const } { : usize = $crate::__private::min_len($name::VALUES); }
+            }
+
+            $crate::__enum_str_item! { PATTERN, [$($opt)*], $vis,
+                { /// A regex alternation of the escaped values and aliases, longest first.
+                } { const } { : &'static str = {
+                    const STRS: &[&str] = &[$(#[cfg $cfg] $value, $($($(#[cfg $cfg] $alias,)*)*)*)*];
+                    const BYTES: [u8; $crate::__private::pattern_len(STRS)] = $crate::__private::pattern(STRS);
+                    match ::core::str::from_utf8(&BYTES) {
+                        ::core::result::Result::Ok(pattern) => pattern,
+                        ::core::result::Result::Err(_) => ::core::panic!("the pattern is not UTF-8"),
+                    }
+                }; }
+            }
+
+            $crate::__enum_str_other! { @as_str [$($other)?] [$($opt)*] $vis $name, $(#[cfg $cfg] ($key, $value)),* }
+            $crate::__enum_str_other! { @index [$($other)?] [$($opt)*] $vis $name, $(#[cfg $cfg] $key),* }
+
+            $crate::__enum_str_item! { name, [$($opt)*], $vis,
+                { /// Returns the identifier of the variant, as opposed to its string value.
+                } { const fn } {
+                    (&self) -> &'static str {
+                        match self {
+                            $(
+                                #[cfg $cfg]
+                                &$name::$key => ::core::stringify!($key),
+                            )*
+                            $(&$name::$other(_) => ::core::stringify!($other),)?
+                        }
+                    }
+                }
+            }
+
+            $crate::__enum_str_item! { from_name, [$($opt)*], $vis,
+                { /// Returns the variant whose identifier is `name`, if there is one.
+                } { fn } {
+                    (name: &str) -> ::core::option::Option<Self> {
+                        match name {
+                            $(
+                                #[cfg $cfg]
+                                ::core::stringify!($key) => ::core::option::Option::Some($name::$key),
+                            )*
+                            _ => ::core::option::Option::None,
+                        }
+                    }
+                }
+            }
+
+            $crate::__enum_str_item! { from_str_ignore_ascii_case, [$($opt)*], $vis,
+                { /// Returns the variant whose value or alias is an ASCII case-insensitive match for
+                  /// `value`, regardless of `#[case_insensitive]`.
+                } { fn } {
+                    (value: &str) -> ::core::option::Option<Self> {
+                        $crate::__enum_str_match! {
+                            @ascii $name, $crate::__enum_str_if! { trim, [$($opt)*], { value.trim() }, { value } },
+                            $(#[cfg $cfg] ($key, $value $($(, [$($alias),*])?)*)),*
+                        }
+                    }
+                }
+            }
+
+            $crate::__enum_str_if! { runtime_lookup, [$($opt)*], {
+                $crate::__enum_str_item! { is_valid, [$($opt)*], $vis,
+                    { /// Returns whether `value` parses to one of the listed variants, without
+                      /// building the parse error. It is not a `const fn`, since the enum
+                      /// normalizes the input or parses through a lookup table.
+                    } { fn } {
+                        (value: &str) -> bool {
+                            $name::__enum_str_variant($crate::__enum_str_if! { trim, [$($opt)*], { value.trim() }, { value } }).is_some()
+                        }
+                    }
+                }
+            }, {
+                $crate::__enum_str_item! { is_valid, [$($opt)*], $vis,
+                    { /// Returns whether `value` parses to one of the listed variants, without
+                      /// building the parse error.
+                    } { const fn } {
+                        (value: &str) -> bool {
+                            const STRS: &[&str] = &[$(#[cfg $cfg] $value, $($($(#[cfg $cfg] $alias,)*)*)*)*];
+                            $crate::__private::contains_str(STRS, value)
+                        }
+                    }
+                }
+            } }
+
+            $crate::__enum_str_item! { from_str_unchecked, [$($opt)*], $vis,
+                { /// Parses `value` without building an error for inputs that match no value, for
+                  /// strings that were already validated.
+                  ///
+                  /// # Safety
+                  ///
+                  /// `value` must be accepted by `from_str`. Other inputs are undefined behavior,
+                  /// and panic when debug assertions are enabled.
+                  #[inline]
+                } { unsafe fn } {
+                    (value: &str) -> Self {
+                        let val = $crate::__enum_str_if! { trim, [$($opt)*], { value.trim() }, { value } };
+                        $crate::__enum_str_other! { @unchecked [$($other)?] $name, val }
+                    }
+                }
+            }
+
+            $crate::__enum_str_item! { strip_prefix, [$($opt)*], $vis,
+                { /// Returns the variant whose value or alias `input` starts with, and the rest of
+                  /// `input`. Values are tried in declaration order and compared exactly.
+                } { fn } {
+                    (input: &str) -> ::core::option::Option<(Self, &str)> {
+                        $(
+                            #[cfg $cfg]
+                            {
+                                if let ::core::option::Option::Some(rest) = input.strip_prefix($value) {
+                                    return ::core::option::Option::Some(($name::$key, rest));
+                                }
+                                $($($(
+                                    if let ::core::option::Option::Some(rest) = input.strip_prefix($alias) {
+                                        return ::core::option::Option::Some(($name::$key, rest));
+                                    }
+                                )*)?)*
+                            }
+                        )*
+                        ::core::option::Option::None
+                    }
+                }
+            }
+
+            $crate::__enum_str_item! { match_longest_at, [$($opt)*], $vis,
+                { /// Returns the variant with the longest value or alias that `input` starts with, and
+                  /// the length of that value in bytes. Values are compared exactly.
+                } { fn } {
+                    (input: &str) -> ::core::option::Option<(Self, usize)> {
+                        let mut longest = ::core::option::Option::None;
+                        let mut len = 0;
+                        $(
+                            #[cfg $cfg]
+                            {
+                                for value in [$value $($($(, $alias)*)*)*] {
+                                    if input.starts_with(value) && (longest.is_none() || value.len() > len) {
+                                        longest = ::core::option::Option::Some($name::$key);
+                                        len = value.len();
+                                    }
+                                }
+                            }
+                        )*
+                        longest.map(|variant| (variant, len))
+                    }
+                }
+            }
+
+            $crate::__enum_str_item! { find_all, [$($opt)*], $vis,
+                { /// Iterates over the values and aliases found in `haystack`, with their byte
+                  /// offsets. Overlapping occurrences are skipped, preferring the leftmost and then
+                  /// the longest one.
+                } { fn } {
+                    (haystack: &str) -> impl ::core::iter::Iterator<Item = (usize, Self)> + '_ {
+                        $crate::__private::find_all(haystack, $name::match_longest_at)
+                    }
+                }
+            }
+
+            $crate::__enum_str_item! { iter, [$($opt)*], $vis,
+                { /// Iterates over every variant in declaration order.
+                } { fn } {
+                    () -> impl ::core::iter::Iterator<Item = $name> {
+                        [$(#[cfg $cfg] $name::$key),*].into_iter()
+                    }
+                }
+            }
+
+            $crate::__enum_str_item! { mapping, [$($opt)*], $vis,
+                { /// The name and value of every variant, in declaration order.
+                } { const fn } {
+                    () -> $crate::Mapping {
+                        const ENTRIES: &[(&str, &str)] = &[$(#[cfg $cfg] (::core::stringify!($key), $value)),*];
+                        $crate::Mapping::new(ENTRIES)
+                    }
+                }
+            }
+
+            $crate::__enum_str_item! { cmp_str, [$($opt)*], $vis,
+                { /// Compares the values of two variants as strings.
+                } { fn } {
+                    (&self, other: &Self) -> ::core::cmp::Ordering {
+                        ::core::cmp::Ord::cmp(self.__enum_str_as_str(), other.__enum_str_as_str())
+                    }
+                }
+            }
+
+            $crate::__enum_str_item! { sorted_variants, [$($opt)*], $vis,
+                { /// Returns every variant ordered by its value rather than by declaration.
+                } { fn } {
+                    () -> [$name; $name::COUNT] {
+                        let mut variants = [$(#[cfg $cfg] $name::$key),*];
+                        variants.sort_unstable_by(|a, b| ::core::cmp::Ord::cmp(a.__enum_str_as_str(), b.__enum_str_as_str()));
+                        variants
+                    }
+                }
+            }
+
+            // The parser behind `FromStr` and the other parsing impls, which keep using it when
+            // `FromStr` is skipped.
+            fn __enum_str_from_str(input: &str) -> ::core::result::Result<Self, $crate::ParseError<Self>> {
+                let val = $crate::__enum_str_if! { trim, [$($opt)*], { input.trim() }, { input } };
+                let variant = $name::__enum_str_variant(val);
+                $crate::__enum_str_other! { @parsed [$($other)?] [$($opt)*] $name, variant, val, input }
+            }
+
+            // Compares the bytes with the values directly when no normalization is needed, so
+            // only inputs that match no value are checked for UTF-8.
+            fn __enum_str_from_bytes(bytes: &[u8]) -> ::core::result::Result<Self, $crate::ParseError<Self>> {
+                let variant = $crate::__enum_str_if! { case_insensitive, [$($opt)*], { ::core::option::Option::None }, {
+                    $crate::__enum_str_if! { trim, [$($opt)*], { ::core::option::Option::None }, {
+                        $crate::__enum_str_if! { nfc, [$($opt)*], { ::core::option::Option::None }, {
+                            $crate::__enum_str_match! {
+                                @bytes $name, bytes,
+                                $(#[cfg $cfg] ($key, $value $($(, [$($alias),*])?)*)),*
+                            }
+                        } }
+                    } }
+                } };
+                match variant {
+                    ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                    ::core::option::Option::None => match ::core::str::from_utf8(bytes) {
+                        ::core::result::Result::Ok(input) => $name::__enum_str_from_str(input),
+                        ::core::result::Result::Err(_) => ::core::result::Result::Err(
+                            $crate::ParseError::from_bytes(::core::stringify!($name), bytes).with_values($name::VALUES),
+                        ),
+                    },
+                }
+            }
+
+            // Matches the already trimmed `val` against the values and aliases.
+            #[deny(unreachable_patterns)]
+            fn __enum_str_variant(val: &str) -> ::core::option::Option<Self> {
+                $crate::__enum_str_if! { nfc, [$($opt)*], {
+                    $crate::__enum_str_nfc! {
+                        $name, val,
+                        $crate::__enum_str_if! { case_insensitive, [$($opt)*], { true }, { false } },
+                        $crate::__enum_str_if! { collapse, [$($opt)*], { true }, { false } },
+                        $(#[cfg $cfg] ($key, $value $($(, [$($alias),*])?)*)),*
+                    }
+                }, { $crate::__enum_str_if! { case_insensitive, [$($opt)*], {
+                    $crate::__enum_str_match! {
+                        @normalized $name, val, true, $crate::__enum_str_if! { collapse, [$($opt)*], { true }, { false } },
+                        $(#[cfg $cfg] ($key, $value $($(, [$($alias),*])?)*)),*
+                    }
+                }, {
+                    $crate::__enum_str_if! { collapse, [$($opt)*], {
+                        $crate::__enum_str_match! {
+                            @normalized $name, val, false, true,
+                            $(#[cfg $cfg] ($key, $value $($(, [$($alias),*])?)*)),*
+                        }
+                    }, {
+                        $crate::__enum_str_if! { phf, [$($opt)*], {
+                            $crate::__enum_str_phf!($name, val, $(#[cfg $cfg] ($key, $value $($(, [$($alias),*])?)*)),*)
+                        }, { $crate::__enum_str_if! { binary_search, [$($opt)*], {
+                            $crate::__enum_str_match! {
+                                @binary_search [$($other)?] $name, val,
+                                $(#[cfg $cfg] ($key, $value $($(, [$($alias),*])?)*)),*
+                            }
+                        }, { $crate::__enum_str_if! { hash_map, [$($opt)*], {
+                            $crate::__enum_str_hash_map!($name, val, $(#[cfg $cfg] ($key, $value $($(, [$($alias),*])?)*)),*)
+                        }, {
+                            $crate::__enum_str_match! {
+                                @exact $name, val,
+                                $(#[cfg $cfg] ($key, $value $($(, [$($alias),*])?)*)),*
+                            }
+                        } } } } } }
+                    } }
+                } } } }
+            }
+        }
+
+        $crate::__enum_str_if! { skip(Display), [$($opt)*], {}, {
+            impl ::core::fmt::Display for $name {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    if f.alternate() {
+                        f.pad($name::name(self))
+                    } else {
+                        f.pad(self.__enum_str_as_str())
+                    }
+                }
+            }
+        } }
+
+        $crate::__enum_str_if! { error_enum, [$($opt)*], {
+            impl ::core::error::Error for $name {}
+        }, {} }
+
+        $crate::__enum_str_if! { skip(AsRef), [$($opt)*], {}, {
+            impl ::core::convert::AsRef<str> for $name {
+                fn as_ref(&self) -> &str {
+                    self.__enum_str_as_str()
+                }
+            }
+        } }
+
+        $crate::__enum_str_if! { skip(FromStr), [$($opt)*], {}, {
+            impl ::core::str::FromStr for $name {
+                type Err = $crate::__enum_str_error!(@type [$($opt)*] $name);
+
+                fn from_str(input: &str) -> ::core::result::Result<Self, $crate::__enum_str_error!(@type [$($opt)*] $name)> {
+                    $crate::__enum_str_error!(@convert [$($opt)*] $name::__enum_str_from_str(input), input)
+                }
+            }
+        } }
+
+        $crate::__enum_str_if! { eq_str, [$($opt)*], {
+            impl ::core::cmp::PartialEq<str> for $name {
+                fn eq(&self, other: &str) -> bool {
+                    self.__enum_str_as_str() == other
+                }
+            }
+
+            impl ::core::cmp::PartialEq<&str> for $name {
+                fn eq(&self, other: &&str) -> bool {
+                    self.__enum_str_as_str() == *other
+                }
+            }
+
+            impl ::core::cmp::PartialEq<$name> for str {
+                fn eq(&self, other: &$name) -> bool {
+                    self == other.__enum_str_as_str()
+                }
+            }
+
+            impl ::core::cmp::PartialEq<$name> for &str {
+                fn eq(&self, other: &$name) -> bool {
+                    *self == other.__enum_str_as_str()
+                }
+            }
+        }, {} }
+
+        $crate::__enum_str_if! { debug_str, [$($opt)*], {
+            impl ::core::fmt::Debug for $name {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_tuple($name::name(self)).field(&self.__enum_str_as_str()).finish()
+                }
+            }
+        }, {} }
+
+        $crate::__enum_str_if! { borrow_str, [$($opt)*], {
+            impl ::core::borrow::Borrow<str> for $name {
+                fn borrow(&self) -> &str {
+                    self.__enum_str_as_str()
+                }
+            }
+
+            impl ::core::hash::Hash for $name {
+                fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                    ::core::hash::Hash::hash(self.__enum_str_as_str(), state)
+                }
+            }
+        }, {} }
+
+        $crate::__enum_str_if! { skip(From), [$($opt)*], {}, {
+            $crate::__enum_str_other! { @static_str [$($other)?] $name }
+            $crate::__enum_str_alloc! { $name, [$($other)?], $(#[cfg $cfg] ($key, $value)),* }
+        } }
+
+        $crate::__enum_str_if! { skip(TryFrom), [$($opt)*], {}, {
+            impl ::core::convert::TryFrom<&str> for $name {
+                type Error = $crate::__enum_str_error!(@type [$($opt)*] $name);
+
+                fn try_from(val: &str) -> ::core::result::Result<Self, $crate::__enum_str_error!(@type [$($opt)*] $name)> {
+                    $crate::__enum_str_error!(@convert [$($opt)*] $name::__enum_str_from_str(val), val)
+                }
+            }
+
+            impl ::core::convert::TryFrom<&[u8]> for $name {
+                type Error = $crate::ParseError<$name>;
+
+                fn try_from(bytes: &[u8]) -> ::core::result::Result<Self, $crate::ParseError<$name>> {
+                    $name::__enum_str_from_bytes(bytes)
+                }
+            }
+
+            $crate::__enum_str_std!($name);
+            $crate::__enum_str_alloc! { @try_from $name }
+            $crate::__enum_str_other! { @try_from_index [$($other)?] $name, u8, u16, u32, u64, usize }
+        } }
+
+        $($($crate::__enum_str_flag! { $name, $cfg, $key, $flag $(= [$($alias),*])? $(($($arg)*))? })*)*
+        $crate::__enum_str_repr! { [$($opt)*] $vis $name, $(#[cfg $cfg] $key),* }
+        $crate::__enum_str_map! { [$($opt)*] $vis $name, [$($other)?], $(#[cfg $cfg] $key),* }
+        $crate::__enum_str_set! { [$($opt)*] $vis $name, [$($other)?] }
+        $crate::__enum_str_column! {
+            @find short, {[$($opt)*] $vis $name, [$($other)?]},
+            [$($({$flag $(= [$($alias),*])? $(($($arg)*))?})*)*]
+            [$(#[cfg $cfg] ($key, $value, [$({$flag $(= [$($alias),*])? $(($($arg)*))?})*])),*]
+        }
+        $crate::__enum_str_column! {
+            @find long, {[$($opt)*] $vis $name, [$($other)?]},
+            [$($({$flag $(= [$($alias),*])? $(($($arg)*))?})*)*]
+            [$(#[cfg $cfg] ($key, $value, [$({$flag $(= [$($alias),*])? $(($($arg)*))?})*])),*]
+        }
+        $crate::__enum_str_code! {
+            @find {[$($opt)*] $vis $name, [$($other)?]},
+            [$($({$flag $(= [$($alias),*])? $(($($arg)*))?})*)*]
+            [$(#[cfg $cfg] ($key, [$({$flag $(= [$($alias),*])? $(($($arg)*))?})*])),*]
+        }
+        $crate::__enum_str_locale! {
+            [$($opt)*] $vis $name, [$($other)?],
+            $(#[cfg $cfg] ($key, $value, [$({$flag $(= [$($alias),*])? $(($($arg)*))?})*])),*
+        }
+        $crate::__enum_str_serde!([$($opt)*] $vis $name);
+        $crate::__enum_str_schemars!($name, [$($other)?], $(#[cfg $cfg] ($key, $value $($(, [$($alias),*])?)*)),*);
+        $crate::__enum_str_clap!($name, [$($other)?], $(#[cfg $cfg] ($key, $value $($(, [$($alias),*])?)*)),*);
+        $crate::__enum_str_diesel!($name);
+        $crate::__enum_str_postgres!($name);
+        $crate::__enum_str_rusqlite!($name);
+        $crate::__enum_str_pyo3!($name);
+        $crate::__enum_str_wasm!($name);
+        $crate::__enum_str_std! { @env [$($opt)*] $vis $name }
+        $crate::__enum_str_alloc! { @list [$($opt)*] $vis $name }
+        $crate::__enum_str_sqlx!($name, [$($other)?]);
+        $crate::__enum_str_strum!($name, $(#[cfg $cfg] $key),*);
+        $crate::__enum_str_arbitrary!($name, $(#[cfg $cfg] $key),*);
+        $crate::__enum_str_proptest!($name, $(#[cfg $cfg] $key),*);
+        $crate::__enum_str_quickcheck!($name, $(#[cfg $cfg] $key),*);
+        $crate::__enum_str_rand!($name, $(#[cfg $cfg] $key),*);
+        $crate::__enum_str_nom!([$($opt)*] $vis $name);
+        $crate::__enum_str_winnow!($vis $name);
+    };
+}
+
+/// Generates the parts of the surface that differ for an enum with a `_ => Other` catch-all
+/// variant, whose string is only borrowed from the value.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_other {
+    // The generated impls call `__enum_str_as_str`, so they keep working when `as_str` is
+    // renamed and another method takes its name.
+    (@as_str [] [$($opt:tt)*] $vis:vis $name:ident, $(#[cfg $cfg:tt] ($key:ident, $value:expr)),*) => {
+        $crate::__enum_str_item! { as_str, [$($opt)*], $vis, {} { const fn } {
+            (&self) -> &'static str {
+                self.__enum_str_as_str()
+            }
+        } }
+
+        $crate::__enum_str_item! { as_bytes, [$($opt)*], $vis,
+            { /// Returns the value of the variant as bytes.
+            } { const fn } {
+                (&self) -> &'static [u8] {
+                    self.__enum_str_as_str().as_bytes()
+                }
+            }
+        }
+
+        const fn __enum_str_as_str(&self) -> &'static str {
+            match self {
+                $(
+                    #[cfg $cfg]
+                    &$name::$key => $value
+                ),*
+            }
+        }
+    };
+    (@as_str [$other:ident] [$($opt:tt)*] $vis:vis $name:ident, $(#[cfg $cfg:tt] ($key:ident, $value:expr)),*) => {
+        $crate::__enum_str_item! { as_str, [$($opt)*], $vis, {} { fn } {
+            (&self) -> &str {
+                self.__enum_str_as_str()
+            }
+        } }
+
+        $crate::__enum_str_item! { as_bytes, [$($opt)*], $vis,
+            { /// Returns the value of the variant as bytes.
+            } { fn } {
+                (&self) -> &[u8] {
+                    self.__enum_str_as_str().as_bytes()
+                }
+            }
+        }
+
+        fn __enum_str_as_str(&self) -> &str {
+            match self {
+                $(
+                    #[cfg $cfg]
+                    &$name::$key => $value,
+                )*
+                &$name::$other(ref value) => value.as_str(),
+            }
+        }
+    };
+
+    (@parsed [] [$($opt:tt)*] $name:ident, $variant:expr, $val:expr, $input:expr) => {
+        $variant.ok_or_else(|| {
+            let err = $crate::ParseError::new(::core::stringify!($name), $input).with_values($name::VALUES);
+            $crate::__enum_str_if! { suggest, [$($opt)*], { err.with_suggestion($val) }, { err } }
+        })
+    };
+    (@parsed [$other:ident] [$($opt:tt)*] $name:ident, $variant:expr, $val:expr, $input:expr) => {
+        ::core::result::Result::Ok($variant.unwrap_or_else(|| $name::$other($crate::__private::String::from($val))))
+    };
+
+    (@unchecked [] $name:ident, $val:expr) => {
+        match $name::__enum_str_variant($val) {
+            ::core::option::Option::Some(variant) => variant,
+            ::core::option::Option::None => {
+                ::core::debug_assert!(false, "from_str_unchecked called with an unknown value");
+                // SAFETY: the caller guarantees that the value is one of the variants.
+                unsafe { ::core::hint::unreachable_unchecked() }
+            }
+        }
+    };
+    (@unchecked [$other:ident] $name:ident, $val:expr) => {
+        $name::__enum_str_variant($val).unwrap_or_else(|| $name::$other($crate::__private::String::from($val)))
+    };
+
+    (@static_str [] $name:ident) => {
+        impl ::core::convert::From<$name> for &'static str {
+            fn from(val: $name) -> Self {
+                val.__enum_str_as_str()
+            }
+        }
+    };
+    (@static_str [$other:ident] $name:ident) => {};
+
+    // The position of the variant among the variants that are not disabled by a `cfg`, read
+    // from the discriminants of an enum with the same variants.
+    (@index [] [$($opt:tt)*] $vis:vis $name:ident, $(#[cfg $cfg:tt] $key:ident),*) => {
+        const fn __enum_str_index(&self) -> usize {
+            #[allow(dead_code)]
+            enum Ordinal {
+                $(#[cfg $cfg] $key,)*
+            }
+
+            match self {
+                $(
+                    #[cfg $cfg]
+                    &$name::$key => Ordinal::$key as usize,
+                )*
+            }
+        }
+
+        const fn __enum_str_from_index(index: usize) -> ::core::option::Option<Self> {
+            #[allow(dead_code)]
+            enum Ordinal {
+                $(#[cfg $cfg] $key,)*
+            }
+
+            $(
+                #[cfg $cfg]
+                if index == Ordinal::$key as usize {
+                    return ::core::option::Option::Some($name::$key);
+                }
+            )*
+            ::core::option::Option::None
+        }
+
+        $crate::__enum_str_item! { index, [$($opt)*], $vis,
+            { /// Returns the position of this variant in declaration order, counting from zero.
+            } { const fn } {
+                (&self) -> usize {
+                    self.__enum_str_index()
+                }
+            }
+        }
+
+        $crate::__enum_str_item! { from_index, [$($opt)*], $vis,
+            { /// Returns the variant at `index` in declaration order, or `None` if there is none.
+            } { const fn } {
+                (index: usize) -> ::core::option::Option<Self> {
+                    $name::__enum_str_from_index(index)
+                }
+            }
+        }
+
+        $crate::__enum_str_item! { next, [$($opt)*], $vis,
+            { /// Returns the variant declared after this one, if there is one.
+            } { const fn } {
+                (&self) -> ::core::option::Option<Self> {
+                    $name::__enum_str_from_index(self.__enum_str_index() + 1)
+                }
+            }
+        }
+
+        $crate::__enum_str_item! { prev, [$($opt)*], $vis,
+            { /// Returns the variant declared before this one, if there is one.
+            } { const fn } {
+                (&self) -> ::core::option::Option<Self> {
+                    match self.__enum_str_index().checked_sub(1) {
+                        ::core::option::Option::Some(index) => $name::__enum_str_from_index(index),
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    }
+                }
+            }
+        }
+
+        $crate::__enum_str_item! { next_wrapping, [$($opt)*], $vis,
+            { /// Returns the variant declared after this one, or the first variant after the last.
+            } { const fn } {
+                (&self) -> Self {
+                    let index = self.__enum_str_index();
+                    let index = if index + 1 == $name::COUNT { 0 } else { index + 1 };
+                    match $name::__enum_str_from_index(index) {
+                        ::core::option::Option::Some(variant) => variant,
+                        ::core::option::Option::None => ::core::unreachable!(),
+                    }
+                }
+            }
+        }
+
+        $crate::__enum_str_item! { prev_wrapping, [$($opt)*], $vis,
+            { /// Returns the variant declared before this one, or the last variant before the first.
+            } { const fn } {
+                (&self) -> Self {
+                    let index = self.__enum_str_index();
+                    let index = if index == 0 { $name::COUNT - 1 } else { index - 1 };
+                    match $name::__enum_str_from_index(index) {
+                        ::core::option::Option::Some(variant) => variant,
+                        ::core::option::Option::None => ::core::unreachable!(),
+                    }
+                }
+            }
+        }
+    };
+    (@index [$other:ident] [$($opt:tt)*] $vis:vis $name:ident, $(#[cfg $cfg:tt] $key:ident),*) => {};
+
+    (@try_from_index [] $name:ident, $($int:ident),*) => {
+        $(
+            impl ::core::convert::TryFrom<$int> for $name {
+                type Error = $crate::IndexError<$name>;
+
+                fn try_from(index: $int) -> ::core::result::Result<Self, $crate::IndexError<$name>> {
+                    ::core::convert::TryFrom::try_from(index)
+                        .ok()
+                        .and_then($name::__enum_str_from_index)
+                        .ok_or_else(|| $crate::IndexError::new(::core::stringify!($name), index as u64, $name::COUNT))
+                }
+            }
+        )*
+    };
+    (@try_from_index [$other:ident] $name:ident, $($int:ident),*) => {};
+}
+
+/// Checks an option given after the value of a variant, and implements `Default` for the
+/// variant marked `default`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_flag {
+    ($name:ident, $cfg:tt, $key:ident, aliases = [$($alias:expr),*]) => {};
+    ($name:ident, $cfg:tt, $key:ident, short = [$($short:expr),*]) => {};
+    ($name:ident, $cfg:tt, $key:ident, long = [$($long:expr),*]) => {};
+    ($name:ident, $cfg:tt, $key:ident, locale($($_locale:tt)*)) => {};
+    ($name:ident, $cfg:tt, $key:ident, $code:literal) => {};
+    ($name:ident, $cfg:tt, $key:ident, default) => {
+        #[cfg $cfg]
+        impl ::core::default::Default for $name {
+            fn default() -> Self {
+                $name::$key
+            }
+        }
+    };
+    ($name:ident, $cfg:tt, $key:ident, $($flag:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "unsupported option `", ::core::stringify!($($flag)*), "` on `", ::core::stringify!($name), "::",
+            ::core::stringify!($key), "`, expected a code, `aliases = [...]`, `short = [...]`, `long = [...]`, `locale(...)` ",
+            "or `default`"
+        ));
+    };
+}
+
+/// Generates `as_short_str` or `as_long_str` when a variant lists `short = [...]` or
+/// `long = [...]`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_column {
+    (@find short, $head:tt, [{short = $_values:tt} $($_flag:tt)*] $variants:tt) => {
+        $crate::__enum_str_column! { @gen short, as_short_str, $head, $variants }
+    };
+    (@find long, $head:tt, [{long = $_values:tt} $($_flag:tt)*] $variants:tt) => {
+        $crate::__enum_str_column! { @gen long, as_long_str, $head, $variants }
+    };
+    (@find $column:ident, $head:tt, [{$($_skip:tt)*} $($flag:tt)*] $variants:tt) => {
+        $crate::__enum_str_column! { @find $column, $head, [$($flag)*] $variants }
+    };
+    (@find $column:ident, $head:tt, [] $variants:tt) => {};
+
+    (
+        @gen $column:ident, $method:ident, {[$($opt:tt)*] $vis:vis $name:ident, []},
+        [$(#[cfg $cfg:tt] ($key:ident, $value:expr, [$($flag:tt)*])),*]
+    ) => {
+        #[allow(dead_code)]
+        impl $name {
+            $crate::__enum_str_item! { $method, [$($opt)*], $vis,
+                { #[doc = ::core::concat!(
+                    "Returns the first `", ::core::stringify!($column), "` value of the variant, or its value if it has none."
+                )] } { const fn } {
+                    (&self) -> &'static str {
+                        match self {
+                            $(
+                                #[cfg $cfg]
+                                &$name::$key => $crate::__enum_str_column! { @value $column, $value, $($flag)* },
+                            )*
+                        }
+                    }
+                }
+            }
+        }
+    };
+    (
+        @gen $column:ident, $method:ident, {[$($opt:tt)*] $vis:vis $name:ident, [$other:ident]},
+        [$(#[cfg $cfg:tt] ($key:ident, $value:expr, [$($flag:tt)*])),*]
+    ) => {
+        #[allow(dead_code)]
+        impl $name {
+            $crate::__enum_str_item! { $method, [$($opt)*], $vis,
+                { #[doc = ::core::concat!(
+                    "Returns the first `", ::core::stringify!($column), "` value of the variant, or its value if it has none."
+                )] } { fn } {
+                    (&self) -> &str {
+                        match self {
+                            $(
+                                #[cfg $cfg]
+                                &$name::$key => $crate::__enum_str_column! { @value $column, $value, $($flag)* },
+                            )*
+                            &$name::$other(ref value) => value.as_str(),
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    (@value short, $value:expr, {short = [$first:expr $(, $_rest:expr)*]} $($_flag:tt)*) => { $first };
+    (@value long, $value:expr, {long = [$first:expr $(, $_rest:expr)*]} $($_flag:tt)*) => { $first };
+    (@value $column:ident, $value:expr, {$($_skip:tt)*} $($flag:tt)*) => {
+        $crate::__enum_str_column! { @value $column, $value, $($flag)* }
+    };
+    (@value $column:ident, $value:expr,) => { $value };
+}
+
+/// Generates `code` and `from_code` when a variant gives an integer code after its value.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_code {
+    (@find $head:tt, [{$_code:literal} $($_flag:tt)*] $variants:tt) => {
+        $crate::__enum_str_code! { @gen $head, $variants }
+    };
+    (@find $head:tt, [{$($_skip:tt)*} $($flag:tt)*] $variants:tt) => {
+        $crate::__enum_str_code! { @find $head, [$($flag)*] $variants }
+    };
+    (@find $head:tt, [] $variants:tt) => {};
+
+    (
+        @gen {[$($opt:tt)*] $vis:vis $name:ident, []},
+        [$(#[cfg $cfg:tt] ($key:ident, [$($flag:tt)*])),*]
+    ) => {
+        #[allow(dead_code)]
+        impl $name {
+            $crate::__enum_str_item! { code, [$($opt)*], $vis,
+                { /// Returns the integer code of the variant.
+                } { const fn } {
+                    (&self) -> u16 {
+                        match self {
+                            $(
+                                #[cfg $cfg]
+                                &$name::$key => $crate::__enum_str_code! { @value $name, $key, $($flag)* },
+                            )*
+                        }
+                    }
+                }
+            }
+
+            $crate::__enum_str_item! { from_code, [$($opt)*], $vis,
+                { /// Returns the variant with the integer code `code`, if there is one.
+                } { const fn } {
+                    (code: u16) -> ::core::option::Option<Self> {
+                        // Two variants with the same code would leave one of them unreachable.
+                        #[deny(unreachable_patterns)]
+                        match code {
+                            $(
+                                #[cfg $cfg]
+                                $crate::__enum_str_code! { @value $name, $key, $($flag)* } => {
+                                    ::core::option::Option::Some($name::$key)
+                                }
+                            )*
+                            _ => ::core::option::Option::None,
+                        }
+                    }
+                }
+            }
+        }
+    };
+    (@gen {[$($opt:tt)*] $vis:vis $name:ident, [$other:ident]}, $variants:tt) => {
+        ::core::compile_error!("an enum with a `_ =>` catch-all cannot have codes");
+    };
+
+    (@value $name:ident, $key:ident, {$code:literal} $($_flag:tt)*) => { $code };
+    (@value $name:ident, $key:ident, {$($_skip:tt)*} $($flag:tt)*) => {
+        $crate::__enum_str_code! { @value $name, $key, $($flag)* }
+    };
+    (@value $name:ident, $key:ident,) => {
+        ::core::compile_error!(::core::concat!(
+            "`", ::core::stringify!($name), "::", ::core::stringify!($key),
+            "` has no code, which the other variants have"
+        ))
+    };
+}
+
+/// Matches `$val` against the values and aliases of every variant, either exactly or after
+/// normalizing case and whitespace, and evaluates to the matching variant.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_match {
+    // The values and aliases are sorted by length at compile time, so only the ones as long
+    // as the input, and starting with the same byte, are compared in full.
+    (@exact $name:ident, $val:expr, $(#[cfg $cfg:tt] ($key:ident, $value:expr $(, [$($alias:expr),*])*)),*) => {{
+        const ENTRIES: &[(&str, fn() -> $name)] = &[
+            $(
+                #[cfg $cfg]
+                ($value, || $name::$key),
+                $($(#[cfg $cfg] ($alias, || $name::$key),)*)*
+            )*
+        ];
+        static TABLE: [(&str, usize); ENTRIES.len()] = $crate::__private::len_table(ENTRIES);
+        match $crate::__private::find_by_len(&TABLE, $val) {
+            ::core::option::Option::Some(i) => ::core::option::Option::Some((ENTRIES[i].1)()),
+            ::core::option::Option::None => ::core::option::Option::None,
+        }
+    }};
+    (
+        @binary_search [] $name:ident, $val:expr,
+        $(#[cfg $cfg:tt] ($key:ident, $value:expr $(, [$($alias:expr),*])*)),*
+    ) => {{
+        const ENTRIES: &[(&str, usize)] = &[
+            $(
+                #[cfg $cfg]
+                ($value, $name::$key.__enum_str_index()),
+                $($(#[cfg $cfg] ($alias, $name::$key.__enum_str_index()),)*)*
+            )*
+        ];
+        static TABLE: [(&str, usize); ENTRIES.len()] = $crate::__private::sorted_table(ENTRIES);
+        match TABLE.binary_search_by(|&(value, _)| value.cmp($val)) {
+            ::core::result::Result::Ok(i) => $name::__enum_str_from_index(TABLE[i].1),
+            ::core::result::Result::Err(_) => ::core::option::Option::None,
+        }
+    }};
+    (@binary_search [$other:ident] $($rest:tt)*) => {
+        ::core::compile_error!("`#[binary_search]` cannot be used on an enum with a `_ =>` catch-all")
+    };
+    (@ascii $name:ident, $val:expr, $(#[cfg $cfg:tt] ($key:ident, $value:expr $(, [$($alias:expr),*])*)),*) => {
+        match $val {
+            $(
+                #[cfg $cfg]
+                v if v.eq_ignore_ascii_case($value) $($(|| v.eq_ignore_ascii_case($alias))*)* => {
+                    ::core::option::Option::Some($name::$key)
+                }
+            )*
+            _ => ::core::option::Option::None,
+        }
+    };
+    (@bytes $name:ident, $val:expr, $(#[cfg $cfg:tt] ($key:ident, $value:expr $(, [$($alias:expr),*])*)),*) => {
+        match $val {
+            $(
+                #[cfg $cfg]
+                v if v == $value.as_bytes() $($(|| v == $alias.as_bytes())*)* => ::core::option::Option::Some($name::$key),
+            )*
+            _ => ::core::option::Option::None,
+        }
+    };
+    (
+        @normalized $name:ident, $val:expr, $ignore_case:expr, $collapse:expr,
+        $(#[cfg $cfg:tt] ($key:ident, $value:expr $(, [$($alias:expr),*])*)),*
+    ) => {
+        match $val {
+            $(
+                #[cfg $cfg]
+                v if $crate::__private::eq_normalized(v, $value, $ignore_case, $collapse)
+                    $($(|| $crate::__private::eq_normalized(v, $alias, $ignore_case, $collapse))*)* => {
+                    ::core::option::Option::Some($name::$key)
+                }
+            )*
+            _ => ::core::option::Option::None,
+        }
+    };
+}
+
+/// Generates `as_char`, `from_char` and `TryFrom<char>` for a `#[char]` enum, whose values are
+/// then turned into one-char strings for the rest of the surface.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_char {
+    // The value of a variant as a `&'static str`, for any constant `char` expression.
+    (@str $value:expr) => {{
+        const BYTES: [u8; char::len_utf8($value)] = $crate::__private::encode_char($value);
+        match ::core::str::from_utf8(&BYTES) {
+            ::core::result::Result::Ok(value) => value,
+            ::core::result::Result::Err(_) => ::core::panic!("a char is not valid UTF-8"),
+        }
+    }};
+    (
+        [$($opt:tt)*] $vis:vis $name:ident, [],
+        $(#[cfg $cfg:tt] ($key:ident, $value:expr $(, $flag:tt $(= [$($alias:expr),* $(,)?])? $(($($arg:tt)*))?)*),)*
+    ) => {
+        #[allow(dead_code)]
+        impl $name {
+            $crate::__enum_str_item! { as_char, [$($opt)*], $vis,
+                { /// Returns the value of the variant.
+                } { const fn } {
+                    (&self) -> char {
+                        match self {
+                            $(
+                                #[cfg $cfg]
+                                &$name::$key => $value,
+                            )*
+                        }
+                    }
+                }
+            }
+
+            $crate::__enum_str_item! { from_char, [$($opt)*], $vis,
+                { /// Returns the variant whose value is `value`, if there is one.
+                } { const fn } {
+                    (value: char) -> ::core::option::Option<Self> {
+                        match value {
+                            $(
+                                #[cfg $cfg]
+                                v if v == $value => ::core::option::Option::Some($name::$key),
+                            )*
+                            _ => ::core::option::Option::None,
+                        }
+                    }
+                }
+            }
+        }
+
+        $crate::__enum_str_if! { skip(TryFrom), [$($opt)*], {}, {
+            impl ::core::convert::TryFrom<char> for $name {
+                type Error = $crate::__enum_str_error!(@type [$($opt)*] $name);
+
+                fn try_from(val: char) -> ::core::result::Result<Self, $crate::__enum_str_error!(@type [$($opt)*] $name)> {
+                    let mut buf = [0; 4];
+                    let val: &str = val.encode_utf8(&mut buf);
+                    $crate::__enum_str_error!(@convert [$($opt)*] $name::__enum_str_from_str(val), val)
+                }
+            }
+        } }
+
+        $crate::__enum_str_impl! {
+            [$($opt)*] $vis $name,
+            $(#[cfg $cfg] ($key, $crate::__enum_str_char!(@str $value) $(, $flag $(= [$($crate::__enum_str_char!(@str $alias)),*])? $(($($arg)*))?)*),)*
+        }
+    };
+    ([$($opt:tt)*] $vis:vis $name:ident, [$other:ident], $($variant:tt)*) => {
+        ::core::compile_error!("a #[char] enum cannot have a `_ =>` catch-all");
+    };
+}
+
+/// Generates `as_<repr>` and `try_from_<repr>` for an enum with a `#[repr(<integer>)]`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_repr {
+    ([{repr(u8)} $($_opt:tt)*] $($rest:tt)*) => { $crate::__enum_str_repr! { @gen u8, as_u8, try_from_u8, $($rest)* } };
+    ([{repr(u16)} $($_opt:tt)*] $($rest:tt)*) => { $crate::__enum_str_repr! { @gen u16, as_u16, try_from_u16, $($rest)* } };
+    ([{repr(u32)} $($_opt:tt)*] $($rest:tt)*) => { $crate::__enum_str_repr! { @gen u32, as_u32, try_from_u32, $($rest)* } };
+    ([{repr(u64)} $($_opt:tt)*] $($rest:tt)*) => { $crate::__enum_str_repr! { @gen u64, as_u64, try_from_u64, $($rest)* } };
+    ([{repr(usize)} $($_opt:tt)*] $($rest:tt)*) => { $crate::__enum_str_repr! { @gen usize, as_usize, try_from_usize, $($rest)* } };
+    ([{repr(i8)} $($_opt:tt)*] $($rest:tt)*) => { $crate::__enum_str_repr! { @gen i8, as_i8, try_from_i8, $($rest)* } };
+    ([{repr(i16)} $($_opt:tt)*] $($rest:tt)*) => { $crate::__enum_str_repr! { @gen i16, as_i16, try_from_i16, $($rest)* } };
+    ([{repr(i32)} $($_opt:tt)*] $($rest:tt)*) => { $crate::__enum_str_repr! { @gen i32, as_i32, try_from_i32, $($rest)* } };
+    ([{repr(i64)} $($_opt:tt)*] $($rest:tt)*) => { $crate::__enum_str_repr! { @gen i64, as_i64, try_from_i64, $($rest)* } };
+    ([{repr(isize)} $($_opt:tt)*] $($rest:tt)*) => { $crate::__enum_str_repr! { @gen isize, as_isize, try_from_isize, $($rest)* } };
+    ([$_skip:tt $($opt:tt)*] $($rest:tt)*) => { $crate::__enum_str_repr! { [$($opt)*] $($rest)* } };
+    ([] $($rest:tt)*) => {};
+
+    (@gen $repr:ty, $as_repr:ident, $try_from_repr:ident, $vis:vis $name:ident, $(#[cfg $cfg:tt] $key:ident),*) => {
+        #[allow(dead_code)]
+        impl $name {
+            /// Returns the discriminant of the variant.
+            $vis const fn $as_repr(&self) -> $repr {
+                match self {
+                    $(
+                        #[cfg $cfg]
+                        &$name::$key => $name::$key as $repr
+                    ),*
+                }
+            }
+
+            /// Returns the variant with the discriminant `value`, if there is one.
+            $vis const fn $try_from_repr(value: $repr) -> ::core::option::Option<Self> {
+                $(
+                    #[cfg $cfg]
+                    if value == $name::$key as $repr {
+                        return ::core::option::Option::Some($name::$key);
+                    }
+                )*
+                ::core::option::Option::None
+            }
+        }
+    };
+}
+
+/// Picks the error type of `FromStr` and `TryFrom<&str>` given with `#[error(...)]`, and
+/// converts a `ParseError` into it.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_error {
+    (@type [{error($err:ty $(, with = $with:path)?)} $($_opt:tt)*] $name:ident) => { $err };
+    (@type [$_skip:tt $($opt:tt)*] $name:ident) => { $crate::__enum_str_error!(@type [$($opt)*] $name) };
+    (@type [] $name:ident) => { $crate::ParseError<$name> };
+
+    (@convert [{error($err:ty, with = $with:path)} $($_opt:tt)*] $result:expr, $input:expr) => {
+        $result.map_err(|_| $with($input))
+    };
+    (@convert [{error($err:ty)} $($_opt:tt)*] $result:expr, $input:expr) => {
+        $result.map_err(::core::convert::From::from)
+    };
+    (@convert [$_skip:tt $($opt:tt)*] $result:expr, $input:expr) => {
+        $crate::__enum_str_error!(@convert [$($opt)*] $result, $input)
+    };
+    (@convert [] $result:expr, $input:expr) => { $result };
+}
+
+/// Declares an inherent item of the generated surface, with the visibility given to it by
+/// `#[vis(...)]` and the name given by `#[rename(...)]`, if any.
+///
+/// The declaration is split into its attributes, the keywords in front of the name and the
+/// rest of it.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_item {
+    ($item:ident, [$($opt:tt)*], $vis:vis, $($decl:tt)*) => {
+        $crate::__enum_str_item! { @scan $item, [$($opt)*], {$vis}, {$item}, $($decl)* }
+    };
+
+    (@scan VARIANTS, [{vis(VARIANTS = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan VARIANTS, [$($opt)*], {$to}, $($rest)* } };
+    (@scan VALUES, [{vis(VALUES = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan VALUES, [$($opt)*], {$to}, $($rest)* } };
+    (@scan TABLE, [{vis(TABLE = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan TABLE, [$($opt)*], {$to}, $($rest)* } };
+    (@scan COUNT, [{vis(COUNT = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan COUNT, [$($opt)*], {$to}, $($rest)* } };
+    (@scan MAX_STR_LEN, [{vis(MAX_STR_LEN = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan MAX_STR_LEN, [$($opt)*], {$to}, $($rest)* } };
+    (@scan MIN_STR_LEN, [{vis(MIN_STR_LEN = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan MIN_STR_LEN, [$($opt)*], {$to}, $($rest)* } };
+    (@scan PATTERN, [{vis(PATTERN = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan PATTERN, [$($opt)*], {$to}, $($rest)* } };
+    (@scan as_str, [{vis(as_str = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan as_str, [$($opt)*], {$to}, $($rest)* } };
+    (@scan as_bytes, [{vis(as_bytes = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan as_bytes, [$($opt)*], {$to}, $($rest)* } };
+    (@scan as_char, [{vis(as_char = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan as_char, [$($opt)*], {$to}, $($rest)* } };
+    (@scan from_char, [{vis(from_char = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan from_char, [$($opt)*], {$to}, $($rest)* } };
+    (@scan as_short_str, [{vis(as_short_str = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan as_short_str, [$($opt)*], {$to}, $($rest)* } };
+    (@scan as_long_str, [{vis(as_long_str = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan as_long_str, [$($opt)*], {$to}, $($rest)* } };
+    (@scan name, [{vis(name = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan name, [$($opt)*], {$to}, $($rest)* } };
+    (@scan from_name, [{vis(from_name = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan from_name, [$($opt)*], {$to}, $($rest)* } };
+    (@scan from_str_ignore_ascii_case, [{vis(from_str_ignore_ascii_case = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan from_str_ignore_ascii_case, [$($opt)*], {$to}, $($rest)* } };
+    (@scan is_valid, [{vis(is_valid = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan is_valid, [$($opt)*], {$to}, $($rest)* } };
+    (@scan from_str_unchecked, [{vis(from_str_unchecked = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan from_str_unchecked, [$($opt)*], {$to}, $($rest)* } };
+    (@scan strip_prefix, [{vis(strip_prefix = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan strip_prefix, [$($opt)*], {$to}, $($rest)* } };
+    (@scan match_longest_at, [{vis(match_longest_at = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan match_longest_at, [$($opt)*], {$to}, $($rest)* } };
+    (@scan parse, [{vis(parse = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan parse, [$($opt)*], {$to}, $($rest)* } };
+    (@scan find_all, [{vis(find_all = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan find_all, [$($opt)*], {$to}, $($rest)* } };
+    (@scan code, [{vis(code = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan code, [$($opt)*], {$to}, $($rest)* } };
+    (@scan from_code, [{vis(from_code = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan from_code, [$($opt)*], {$to}, $($rest)* } };
+    (@scan from_env, [{vis(from_env = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan from_env, [$($opt)*], {$to}, $($rest)* } };
+    (@scan parse_list, [{vis(parse_list = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan parse_list, [$($opt)*], {$to}, $($rest)* } };
+    (@scan join, [{vis(join = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan join, [$($opt)*], {$to}, $($rest)* } };
+    (@scan mapping, [{vis(mapping = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan mapping, [$($opt)*], {$to}, $($rest)* } };
+    (@scan index, [{vis(index = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan index, [$($opt)*], {$to}, $($rest)* } };
+    (@scan from_index, [{vis(from_index = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan from_index, [$($opt)*], {$to}, $($rest)* } };
+    (@scan next, [{vis(next = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan next, [$($opt)*], {$to}, $($rest)* } };
+    (@scan prev, [{vis(prev = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan prev, [$($opt)*], {$to}, $($rest)* } };
+    (@scan next_wrapping, [{vis(next_wrapping = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan next_wrapping, [$($opt)*], {$to}, $($rest)* } };
+    (@scan prev_wrapping, [{vis(prev_wrapping = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan prev_wrapping, [$($opt)*], {$to}, $($rest)* } };
+    (@scan cmp_str, [{vis(cmp_str = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan cmp_str, [$($opt)*], {$to}, $($rest)* } };
+    (@scan sorted_variants, [{vis(sorted_variants = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan sorted_variants, [$($opt)*], {$to}, $($rest)* } };
+    (@scan as_str_locale, [{vis(as_str_locale = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan as_str_locale, [$($opt)*], {$to}, $($rest)* } };
+    (@scan from_str_any_locale, [{vis(from_str_any_locale = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan from_str_any_locale, [$($opt)*], {$to}, $($rest)* } };
+    (@scan iter, [{vis(iter = $to:vis)} $($opt:tt)*], $vis:tt, $($rest:tt)*) => { $crate::__enum_str_item! { @scan iter, [$($opt)*], {$to}, $($rest)* } };
+    (@scan as_str, [{rename(as_str = $to:ident)} $($opt:tt)*], $vis:tt, $name:tt, $($rest:tt)*) => {
+        $crate::__enum_str_item! { @scan as_str, [$($opt)*], $vis, {$to}, $($rest)* }
+    };
+    (@scan parse, [{rename(parse = $to:ident)} $($opt:tt)*], $vis:tt, $name:tt, $($rest:tt)*) => {
+        $crate::__enum_str_item! { @scan parse, [$($opt)*], $vis, {$to}, $($rest)* }
+    };
+    (@scan $item:ident, [$_skip:tt $($opt:tt)*], $($rest:tt)*) => {
+        $crate::__enum_str_item! { @scan $item, [$($opt)*], $($rest)* }
+    };
+    (@scan $item:ident, [], {$vis:vis}, {$name:ident}, {$($attr:tt)*} {$($kw:tt)*} {$($decl:tt)*}) => {
+        $($attr)*
+        $vis $($kw)* $name $($decl)*
+    };
+}
+
+/// Expands to the `then` tokens if `$opt` is among the options collected by `enum_str!`,
+/// and to the `else` tokens otherwise.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_if {
+    (case_insensitive, [{case_insensitive} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    (eq_str, [{eq_str} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    (borrow_str, [{borrow_str} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    (debug_str, [{debug_str} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    (phf, [{phf} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    (binary_search, [{binary_search} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    (hash_map, [{hash_map} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    (trim, [{trim} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    (collapse, [{collapse} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    (suggest, [{suggest} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    (char, [{char} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    (impl_only, [{impl_only} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    // Whether parsing normalizes the input or uses a table that cannot be read in a `const fn`.
+    (runtime_lookup, [{trim} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    (runtime_lookup, [{collapse} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    (runtime_lookup, [{case_insensitive} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    (runtime_lookup, [{nfc} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    (runtime_lookup, [{phf} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    (runtime_lookup, [{hash_map} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    (error_enum, [{error_enum} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    (nfc, [{nfc} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    (serde(names), [{serde(names)} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    (skip(Display), [{skip(Display)} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    (skip(FromStr), [{skip(FromStr)} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    (skip(AsRef), [{skip(AsRef)} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    (skip(From), [{skip(From)} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    (skip(TryFrom), [{skip(TryFrom)} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    (skip(Serialize), [{skip(Serialize)} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    (skip(Deserialize), [{skip(Deserialize)} $($_opt:tt)*], {$($then:tt)*}, $else:tt) => { $($then)* };
+    ($name:ident $(($arg:ident))?, [$_skip:tt $($opt:tt)*], $then:tt, $else:tt) => {
+        $crate::__enum_str_if! { $name $(($arg))?, [$($opt)*], $then, $else }
+    };
+    ($name:ident $(($arg:ident))?, [], $then:tt, {$($else:tt)*}) => { $($else)* };
+}
+
+#[cfg(test)]
+mod test {
+    crate::enum_str_tests!(Fruit, Level, Gem);
+
+    #[cfg(feature = "alloc")]
+    mod catch_all_suite {
+        use super::Protocol;
+
+        crate::enum_str_tests!(Protocol);
+    }
+
+    // The suite only needs the public surface, so it runs outside the module of the enum.
+    mod sibling_suite {
+        use super::http::Status;
+
+        crate::enum_str_tests!(Status(as_str = as_code));
+    }
+
+    mod http {
+        enum_str! {
+            #[rename(as_str = as_code)]
+            pub Status,
+            (Ok, "OK"),
+            (NotFound, "NOT_FOUND"),
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    use std::boxed::Box;
+    use std::str::FromStr;
+    #[cfg(feature = "alloc")]
+    use std::string::String;
+    use std::string::ToString;
+    use std::vec;
+    use std::vec::Vec;
+
+    enum_str! {
+        Fruit,
+        (Apple, "🍎"),
+        (Pineapple, "🍍"),
+        (Strawberry, "🍓"),
+    }
+
+    #[test]
+    fn test_as_str() {
+        assert_eq!("🍎", Fruit::Apple.as_str());
+    }
+
+    #[test]
+    fn test_as_str_const() {
+        const APPLE: &str = Fruit::Apple.as_str();
+        assert_eq!("🍎", APPLE);
+    }
+
+    #[test]
+    fn test_as_str_static() {
+        let value: &'static str = {
+            let fruit = Fruit::Pineapple;
+            fruit.as_str()
+        };
+        assert_eq!("🍍", value);
+    }
+
+    #[test]
+    fn test_name() {
+        assert_eq!("Apple", Fruit::Apple.name());
+        assert_eq!("Err", Outcome::Err.name());
+        assert_eq!("🍓", Fruit::Strawberry.as_str());
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(Some(Fruit::Pineapple), Fruit::from_name("Pineapple"));
+        assert_eq!(Some(Outcome::Error), Outcome::from_name("Error"));
+        assert_eq!(None, Fruit::from_name("🍍"));
+        assert_eq!(None, Fruit::from_name("pineapple"));
+    }
+
+    #[test]
+    fn test_from_str_ignore_ascii_case() {
+        assert_eq!(
+            Some(Greeting::Gruss),
+            Greeting::from_str_ignore_ascii_case("grÜss")
+        );
+        assert_eq!(None, Greeting::from_str_ignore_ascii_case("grüss"));
+        assert_eq!(Some(Answer::Yes), Answer::from_str_ignore_ascii_case("Y"));
+        assert!(Market::from_str("APPLE").is_err());
+        assert_eq!(
+            Some(Market::Apple),
+            Market::from_str_ignore_ascii_case("APPLE")
+        );
+    }
+
+    #[test]
+    fn test_strip_prefix() {
+        assert_eq!(
+            Some((Fruit::Apple, " and 🍍")),
+            Fruit::strip_prefix("🍎 and 🍍")
+        );
+        assert_eq!(Some((Answer::Yes, "")), Answer::strip_prefix("true"));
+        assert_eq!(Some((Answer::No, "pe")), Answer::strip_prefix("nope"));
+        assert_eq!(Some((Answer::No, "ah")), Answer::strip_prefix("nah"));
+        assert_eq!(None, Fruit::strip_prefix(" 🍎"));
+        assert_eq!(None, Market::strip_prefix("durian"));
+    }
+
+    enum_str! {
+        Operator,
+        (Greater, ">"),
+        (GreaterEqual, ">="),
+        (Shift, ">>", aliases = [">>>"]),
+    }
+
+    #[test]
+    fn test_match_longest_at() {
+        assert_eq!(
+            Some((Operator::GreaterEqual, 2)),
+            Operator::match_longest_at(">= 1")
+        );
+        assert_eq!(
+            Some((Operator::Greater, 1)),
+            Operator::match_longest_at("> 1")
+        );
+        assert_eq!(
+            Some((Operator::Shift, 3)),
+            Operator::match_longest_at(">>>=")
+        );
+        assert_eq!(
+            Some((Operator::Greater, "= 1")),
+            Operator::strip_prefix(">= 1")
+        );
+        assert_eq!(None, Operator::match_longest_at("<"));
+    }
+
+    #[test]
+    fn test_find_all() {
+        let found: Vec<_> = Operator::find_all("a >= b > c >>> d").collect();
+        assert_eq!(
+            vec![
+                (2, Operator::GreaterEqual),
+                (7, Operator::Greater),
+                (11, Operator::Shift)
+            ],
+            found
+        );
+        let found: Vec<_> = Answer::find_all("yes, no").collect();
+        assert_eq!(vec![(0, Answer::Yes), (5, Answer::No)], found);
+        assert_eq!(None, Fruit::find_all("apple").next());
+    }
+
+    #[test]
+    fn test_is_valid() {
+        const { assert!(Fruit::is_valid("🍎")) };
+        const { assert!(Mime::is_valid("text")) };
+        const { assert!(!Market::is_valid("durio")) };
+        assert!(Fruit::is_valid("🍓"));
+        assert!(!Fruit::is_valid("Strawberry"));
+        assert!(!Fruit::is_valid(" 🍓"));
+    }
+
+    #[test]
+    fn test_from_str_unchecked() {
+        // SAFETY: all of these are values of the enums.
+        unsafe {
+            assert_eq!(Fruit::Pineapple, Fruit::from_str_unchecked("🍍"));
+            assert_eq!(Answer::Yes, Answer::from_str_unchecked("TRUE"));
+        }
+    }
+
+    #[test]
+    fn test_bytes() {
+        assert_eq!(b"\xf0\x9f\x8d\x8e", Fruit::Apple.as_bytes());
+        assert_eq!(Ok(Fruit::Pineapple), Fruit::try_from("🍍".as_bytes()));
+        assert_eq!(Ok(Answer::Yes), Answer::try_from(&b"TRUE"[..]));
+        assert!(Fruit::try_from(&b"\xf0\x9f"[..]).is_err());
+    }
+
+    enum_str! {
+        #[char]
+        #[case_insensitive]
+        Flag,
+        (Verbose, 'v'),
+        (Quiet, 'q'),
+        #[cfg(any())]
+        (Force, 'f'),
+    }
+
+    #[test]
+    fn test_char() {
+        const VERBOSE: char = Flag::Verbose.as_char();
+        assert_eq!('v', VERBOSE);
+        assert_eq!("q", Flag::Quiet.as_str());
+        assert_eq!(&["v", "q"], Flag::VALUES);
+        assert_eq!(None, Flag::from_char('V'));
+        assert_eq!(None, Flag::from_char('f'));
+        assert_eq!(Ok(Flag::Verbose), Flag::try_from('V'));
+        assert_eq!(Flag::Quiet, Flag::from_str("Q").unwrap());
+        assert!(Flag::try_from('x').is_err());
+    }
+
+    static PEAR: &str = "fruit:pear";
+    static FIG: &str = "fruit:fig";
+
+    enum_str! {
+        Grove,
+        (Pear, PEAR, aliases = [FIG]),
+        (Fig, "fig"),
+    }
+
+    const FRUIT_PREFIX: &str = "fruit:";
+    const CHERRY: &str = "fruit:cherry";
+    const EAST: char = 'E';
+
+    enum_str! {
+        Orchard,
+        (Cherry, CHERRY, aliases = [concat!("fruit:", "sour-cherry")]),
+        (Plum, concat!("fruit:", "plum"), aliases = [FRUIT_PREFIX]),
+    }
+
+    enum_str! {
+        #[char]
+        Compass,
+        (East, EAST),
+        (West, 'W', aliases = [EAST.to_ascii_lowercase()]),
+    }
+
+    #[test]
+    fn test_const_values() {
+        assert_eq!("fruit:cherry", Orchard::Cherry.as_str());
+        assert_eq!(&["fruit:cherry", "fruit:plum"], Orchard::VALUES);
+        assert_eq!(Ok(Orchard::Cherry), Orchard::from_str("fruit:sour-cherry"));
+        assert_eq!(Ok(Orchard::Plum), Orchard::from_str("fruit:"));
+        assert_eq!('E', Compass::East.as_char());
+        assert_eq!("E", Compass::East.as_str());
+        assert_eq!(Some(Compass::East), Compass::from_char('E'));
+        assert_eq!(Ok(Compass::West), Compass::try_from('e'));
+        assert_eq!("fruit:pear", Grove::Pear.as_str());
+        assert_eq!(Ok(Grove::Pear), Grove::from_str("fruit:fig"));
+        assert_eq!(Ok(Grove::Fig), Grove::from_str("fig"));
+        assert!(Grove::is_valid("fruit:pear"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_os_str() {
+        use std::ffi::{OsStr, OsString};
+
+        assert_eq!(Ok(Fruit::Apple), Fruit::try_from(OsStr::new("🍎")));
+        assert_eq!(
+            Ok(Answer::No),
+            Answer::try_from(OsString::from("N").as_os_str())
+        );
+        assert!(Fruit::try_from(OsStr::new("🍌")).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_bytes_error() {
+        let err = Fruit::try_from(&b"\xf0\x9f"[..]).unwrap_err();
+        assert_eq!("\u{fffd}", err.input());
+        assert_eq!(
+            Protocol::Unknown("ftp".into()),
+            Protocol::try_from(&b"ftp"[..]).unwrap()
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_is_valid_catch_all() {
+        assert!(Protocol::is_valid(" HTTP "));
+        assert!(!Protocol::is_valid("ftp"));
+    }
+
+    #[test]
+    fn test_as_ref() {
+        fn len(value: impl AsRef<str>) -> usize {
+            value.as_ref().len()
+        }
+        assert_eq!("🍓".len(), len(Fruit::Strawberry));
+    }
+
+    #[test]
+    fn test_into_static_str() {
+        fn label(value: impl Into<&'static str>) -> &'static str {
+            value.into()
+        }
+        assert_eq!("🍍", label(Fruit::Pineapple));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_into_string() {
+        let value: String = Fruit::Apple.into();
+        assert_eq!("🍎", value);
+    }
+
+    enum_str! {
+        #[eq_str]
+        Vegetable,
+        (Carrot, "🥕"),
+        (Corn, "🌽"),
+    }
+
+    #[test]
+    fn test_eq_str() {
+        assert!(Vegetable::Carrot == "🥕");
+        assert!("🥕" == Vegetable::Carrot);
+        assert!(Vegetable::Carrot == *"🥕");
+        assert!(*"🥕" == Vegetable::Carrot);
+        assert!(Vegetable::Corn != "🥕");
+        assert_eq!(Vegetable::Corn, Vegetable::from_str("🌽").unwrap());
+    }
+
+    enum_str! {
+        #[borrow_str]
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        Color,
+        (Red, "red"),
+        (Blue, "blue"),
+    }
+
+    #[test]
+    fn test_borrow_str() {
+        use std::collections::{HashMap, HashSet};
+
+        let map = HashMap::from([(Color::Red, 1), (Color::Blue, 2)]);
+        assert_eq!(Some(&1), map.get("red"));
+        assert_eq!(Some(&2), map.get(&Color::Blue));
+        assert_eq!(None, map.get("green"));
+
+        let set = HashSet::from([Color::Blue]);
+        assert!(set.contains("blue"));
+    }
+
+    enum_str! {
+        #[debug_str]
+        Gem,
+        (Ruby, "0xE0115F"),
+        (Sapphire, "0x0F52BA"),
+    }
+
+    #[test]
+    fn test_debug_str() {
+        assert_eq!(r#"Ruby("0xE0115F")"#, std::format!("{:?}", Gem::Ruby));
+        assert_eq!(
+            "Sapphire(\n    \"0x0F52BA\",\n)",
+            std::format!("{:#?}", Gem::Sapphire)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_debug_str_catch_all() {
+        enum_str! {
+            #[debug_str]
+            Mineral,
+            (Quartz, "SiO2"),
+            _ => Other,
+        }
+
+        assert_eq!(r#"Quartz("SiO2")"#, std::format!("{:?}", Mineral::Quartz));
+        assert_eq!(
+            r#"Other("NaCl")"#,
+            std::format!("{:?}", "NaCl".parse::<Mineral>().unwrap())
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_try_from_string() {
+        use std::string::String;
+
+        assert_eq!(Ok(Fruit::Apple), Fruit::try_from(String::from("🍎")));
+        assert_eq!(Err(String::from("🍌")), Fruit::try_from(String::from("🍌")));
+        assert_eq!(
+            Ok(Protocol::Unknown("gopher".into())),
+            Protocol::try_from(String::from("gopher"))
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_into_cow() {
+        use std::borrow::Cow;
+
+        let label: Cow<'static, str> = Fruit::Apple.into();
+        assert!(matches!(label, Cow::Borrowed("🍎")));
+
+        let label: Cow<'static, str> = Protocol::Http.into();
+        assert!(matches!(label, Cow::Borrowed("http")));
+        let label: Cow<'static, str> = Protocol::from_str("gopher").unwrap().into();
+        assert!(matches!(label, Cow::Owned(ref value) if value == "gopher"));
+    }
+
+    const SUN_DISCRIMINANT: isize = 7;
+
+    enum_str! {
+        mod sky,
+        #[map(SkyMap)]
+        pub(super) Sky,
+        (Sun = SUN_DISCRIMINANT, "sun"),
+        (Moon, "moon"),
+    }
+
+    #[test]
+    fn test_mod() {
+        assert_eq!(sky::Sky::Moon, "moon".parse().unwrap());
+        assert_eq!("sun", sky::Sky::Sun.as_str());
+        assert_eq!(8, sky::Sky::Moon as isize);
+        let map = sky::SkyMap::from_fn(|sky| sky.as_str().len());
+        assert_eq!(&4, map.get(&sky::Sky::Moon));
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[repr(i32)]
+    enum Planet {
+        Mercury = 1,
+        Venus = 2,
+        #[cfg(any())]
+        Vulcan = 3,
+    }
+
+    enum_str! {
+        #[case_insensitive]
+        impl Planet {
+            Mercury => "mercury",
+            Venus => "venus",
+            #[cfg(any())]
+            Vulcan => "vulcan",
+        }
+    }
+
+    #[test]
+    fn test_impl_only() {
+        assert_eq!("venus", Planet::Venus.as_str());
+        assert_eq!(Ok(Planet::Mercury), Planet::from_str("MERCURY"));
+        assert_eq!(&["mercury", "venus"], Planet::VALUES);
+        assert_eq!(2, Planet::Venus as i32);
+        assert!(Planet::from_str("vulcan").is_err());
+    }
+
+    crate::enum_str_error! {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        QueueError,
+        (Full, "the queue is full", 1),
+        (Closed, "the queue is closed", 2),
+    }
+
+    #[test]
+    fn test_error_enum() {
+        fn message(err: &dyn core::error::Error) -> std::string::String {
+            std::format!("{err}")
+        }
+
+        assert_eq!("the queue is full", message(&QueueError::Full));
+        assert_eq!(2, QueueError::Closed.code());
+        assert_eq!(Ok(QueueError::Closed), "the queue is closed".parse());
+    }
+
+    #[test]
+    fn test_to_string() {
+        assert_eq!("🍎", Fruit::Apple.to_string().as_str());
+    }
+
+    #[test]
+    fn test_display_formatting() {
+        assert_eq!("[  low]", std::format!("[{:>5}]", Level::Low));
+        assert_eq!("[high-]", std::format!("[{:-<5}]", Level::High));
+        assert_eq!("[hi]", std::format!("[{:.2}]", Level::High));
+        assert_eq!("High", std::format!("{:#}", Level::High));
+        assert_eq!("[  Low]", std::format!("[{:>#5}]", Level::Low));
+    }
+
+    #[test]
+    fn test_iter() {
+        let all: Vec<Fruit> = Fruit::iter().collect();
+        assert_eq!(vec![Fruit::Apple, Fruit::Pineapple, Fruit::Strawberry], all);
+    }
+
+    #[test]
+    fn test_variants_and_values() {
+        assert_eq!(
+            &[Fruit::Apple, Fruit::Pineapple, Fruit::Strawberry],
+            Fruit::VARIANTS
+        );
+        assert_eq!(&["🍎", "🍍", "🍓"], Fruit::VALUES);
+        assert_eq!(&["yes", "no"], Answer::VALUES);
+    }
+
+    #[test]
+    fn test_table() {
+        assert_eq!(
+            &[
+                (Fruit::Apple, "🍎"),
+                (Fruit::Pineapple, "🍍"),
+                (Fruit::Strawberry, "🍓")
+            ],
+            Fruit::TABLE
+        );
+        assert_eq!(&[(Flag::Verbose, "v"), (Flag::Quiet, "q")], Flag::TABLE);
+        for (variant, value) in Fruit::TABLE {
+            assert_eq!(*value, variant.as_str());
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_table_catch_all() {
+        assert_eq!(
+            &[(Protocol::Http, "http"), (Protocol::Https, "https")],
+            Protocol::TABLE
+        );
+    }
+
+    #[test]
+    fn test_mapping() {
+        const MAPPING: crate::Mapping = Fruit::mapping();
+        assert_eq!(3, MAPPING.len());
+        assert_eq!(
+            &[("Apple", "🍎"), ("Pineapple", "🍍"), ("Strawberry", "🍓")],
+            MAPPING.entries()
+        );
+        assert_eq!(Some("v"), Flag::mapping().get("Verbose"));
+        assert_eq!(None, Flag::mapping().get("Force"));
+        assert_eq!(
+            r#"{"Apple": "🍎", "Pineapple": "🍍", "Strawberry": "🍓"}"#,
+            std::format!("{MAPPING:?}")
+        );
+    }
+
+    #[cfg(all(feature = "serde", feature = "alloc"))]
+    #[test]
+    fn test_mapping_json() {
+        assert_eq!(
+            serde_json::json!({ "Http": "http", "Https": "https" }),
+            serde_json::to_value(Protocol::mapping()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_count() {
+        let counts = [0u8; Fruit::COUNT];
+        assert_eq!(3, counts.len());
+        assert_eq!(2, Answer::COUNT);
+    }
+
+    #[test]
+    fn test_pattern() {
+        enum_str! {
+            Token,
+            (Dot, "."),
+            (Range, ".."),
+            (Question, "?", aliases = ["(?)"]),
+            (Caret, "^x"),
+        }
+
+        assert_eq!(r"\(\?\)|\.\.|\^x|\.|\?", Token::PATTERN);
+        assert_eq!("🍎|🍍|🍓", Fruit::PATTERN);
+    }
+
+    #[test]
+    fn test_str_len() {
+        let buf = [0u8; Fruit::MAX_STR_LEN];
+        assert_eq!(4, buf.len());
+        assert_eq!(3, Answer::MAX_STR_LEN);
+        assert_eq!(2, Answer::MIN_STR_LEN);
+        assert_eq!(4, Fruit::MIN_STR_LEN);
+    }
+
+    #[test]
+    fn test_from_str_ok() {
+        assert_eq!(Fruit::Apple, Fruit::from_str("🍎").unwrap());
+        assert_eq!(Fruit::Strawberry, Fruit::from_str("🍓").unwrap());
+    }
+
+    #[test]
+    fn test_from_str_err() {
+        assert!(Fruit::from_str("Strawberry").is_err());
+    }
+
+    #[test]
+    fn test_try_from_str() {
+        assert_eq!(Fruit::Apple, Fruit::try_from("🍎").unwrap());
+        let err = Fruit::try_from("🍌").unwrap_err();
+        assert_eq!(Fruit::from_str("🍌").unwrap_err(), err);
+    }
+
+    enum_str! {
+        #[suggest]
+        Tool,
+        (Hammer, "hammer"),
+        (Saw, "saw"),
+        (Wrench, "wrench"),
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_suggestion() {
+        let err = Tool::from_str("Hamer").unwrap_err();
+        assert_eq!(Some("hammer"), err.suggestion());
+        assert_eq!(
+            "unknown Tool: \"Hamer\", did you mean \"hammer\"?",
+            err.to_string()
+        );
+
+        let err = Tool::from_str("screwdriver").unwrap_err();
+        assert_eq!(None, err.suggestion());
+        assert_eq!(
+            "unknown Tool: \"screwdriver\", expected one of \"hammer\", \"saw\", \"wrench\"",
+            err.to_string()
+        );
+        assert_eq!(None, Fruit::from_str("🍏").unwrap_err().suggestion());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_parse_error() {
+        let err = Fruit::from_str("Strawberry").unwrap_err();
+        assert_eq!("Fruit", err.name());
+        assert_eq!("Strawberry", err.input());
+        assert_eq!(
+            "unknown Fruit: \"Strawberry\", expected one of \"🍎\", \"🍍\", \"🍓\"",
+            err.to_string()
+        );
+        assert_eq!(&["🍎", "🍍", "🍓"], err.values());
+
+        let boxed: Box<dyn std::error::Error + Send + Sync> = Box::new(err);
+        assert_eq!(
+            "unknown Fruit: \"Strawberry\", expected one of \"🍎\", \"🍍\", \"🍓\"",
+            boxed.to_string()
+        );
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    #[test]
+    fn test_parse_error_without_alloc() {
+        let err = Fruit::from_str("Strawberry").unwrap_err();
+        assert_eq!("Fruit", err.name());
+        assert_eq!(
+            "unknown Fruit, expected one of \"🍎\", \"🍍\", \"🍓\"",
+            err.to_string()
+        );
+    }
+
+    enum_str! {
+        Outcome,
+        (Ok, "ok"),
+        (Err, "err"),
+        (Error, "error"),
+    }
+
+    #[test]
+    fn test_variants_shadowing_std_names() {
+        assert_eq!(Outcome::Err, Outcome::from_str("err").unwrap());
+        assert_eq!(Outcome::Error, Outcome::try_from("error").unwrap());
+        assert!(Outcome::from_str("fine").is_err());
+    }
+
+    enum_str! {
+        #[repr(i16)]
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        Temperature,
+        (Freezing = -10, "freezing"),
+        (Mild, "mild"),
+        (Hot = 30, "hot"),
+    }
+
+    #[test]
+    fn test_discriminants() {
+        assert_eq!(-10, Temperature::Freezing as i16);
+        assert_eq!(-9, Temperature::Mild.as_i16());
+        assert_eq!(Some(Temperature::Hot), Temperature::try_from_i16(30));
+        assert_eq!(None, Temperature::try_from_i16(0));
+        assert_eq!(Temperature::Mild, Temperature::from_str("mild").unwrap());
+    }
+
+    mod public {
+        enum_str! {
+            #[non_exhaustive]
+            pub Color,
+            (Red, "red"),
+            (Green, "green"),
+        }
+
+        enum_str! {
+            #[vis(from_name = pub(self), VALUES = pub(crate))]
+            pub Shade,
+            (Light, "light"),
+            (Dark, "dark"),
+        }
+
+        pub fn shade_named(name: &str) -> Option<Shade> {
+            Shade::from_name(name)
+        }
+    }
+
+    enum_str! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        Level,
+        (Low, "low"),
+        (High, "high"),
+    }
+
+    enum_str! {
+        /// Directions on a compass.
+        #[derive(Debug, Clone, PartialEq)]
+        #[allow(clippy::upper_case_acronyms)]
+        Direction,
+        /// Up on the map.
+        (North, "N"),
+        #[doc = "Down on the map."]
+        (South, "S"),
+    }
+
+    #[test]
+    fn test_attributes() {
+        assert_eq!("N", Direction::North.as_str());
+        assert_eq!(Direction::South, Direction::from_str("S").unwrap());
+    }
+
+    enum_str! {
+        #[case_insensitive]
+        Greeting,
+        (Hello, "Hello"),
+        (Gruss, "GRÜSS"),
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        assert_eq!(Greeting::Hello, Greeting::from_str("hELLO").unwrap());
+        assert_eq!(Greeting::Gruss, Greeting::from_str("grüss").unwrap());
+        assert_eq!("GRÜSS", Greeting::Gruss.as_str());
+        assert!(Greeting::from_str("hallo").is_err());
+        assert!(Fruit::from_str("🍎").is_ok());
+    }
+
+    enum_str! {
+        #[case_insensitive]
+        Folded,
+        (Street, "straße"),
+        (Ours, "ΣΑΣ"),
+        (Kilo, "k"),
+        (Dot, "i"),
+    }
+
+    #[test]
+    fn test_case_folding() {
+        assert_eq!(Ok(Folded::Street), Folded::from_str("STRASSE"));
+        assert_eq!(Ok(Folded::Street), Folded::from_str("strasse"));
+        assert_eq!(Ok(Folded::Street), Folded::from_str("STRA\u{1E9E}E"));
+        assert_eq!(Ok(Folded::Ours), Folded::from_str("σας"));
+        assert_eq!(Ok(Folded::Ours), Folded::from_str("σασ"));
+        assert_eq!(Ok(Folded::Kilo), Folded::from_str("\u{212A}"));
+        assert_eq!(Ok(Folded::Dot), Folded::from_str("I"));
+        assert!(Folded::from_str("\u{131}").is_err());
+        assert_eq!("straße", Folded::Street.as_str());
+    }
+
+    enum_str! {
+        #[case_insensitive]
+        Answer,
+        (Yes, "yes", aliases = ["y", "true"]),
+        (No, "no", aliases = ["n"]),
+    }
+
+    enum_str! {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        Size,
+        (Small, "S", aliases = ["small"]),
+        (Medium, "M", aliases = ["medium"], default),
+        (Large, "L"),
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(Size::Medium, Size::default());
+        assert_eq!(Size::Medium, Size::from_str("medium").unwrap());
+    }
+
+    #[cfg(feature = "alloc")]
+    enum_str! {
+        #[trim]
+        Protocol,
+        (Http, "http", aliases = ["HTTP"]),
+        (Https, "https"),
+        _ => Unknown,
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_catch_all() {
+        assert_eq!(Protocol::Http, Protocol::from_str("HTTP").unwrap());
+        let gopher = Protocol::from_str(" gopher ").unwrap();
+        assert_eq!(Protocol::Unknown(String::from("gopher")), gopher);
+        assert_eq!("gopher", gopher.as_str());
+        assert_eq!("gopher", gopher.to_string());
+        assert_eq!("Unknown", gopher.name());
+        assert_eq!(2, Protocol::COUNT);
+        assert_eq!(None, Protocol::from_name("Unknown"));
+        let text: String = gopher.into();
+        assert_eq!("gopher", text);
+    }
+
+    enum_str! {
+        Market,
+        (Apple, "apple"),
+        /// Only sold when the season allows it.
+        #[cfg(any())]
+        (Durian, "durian", aliases = ["durio"], default),
+        #[doc = "Kept by every build."]
+        #[cfg(all())]
+        #[allow(unused)]
+        (Fig, "fig"),
+    }
+
+    enum_str! {
+        #[map(RoomMap)]
+        Room,
+        (Kitchen, "kitchen"),
+        #[cfg(any())]
+        (Cellar, "cellar"),
+        (Attic, "attic"),
+    }
+
+    #[test]
+    fn test_map() {
+        let mut lights: RoomMap<bool> = RoomMap::default();
+        lights[Room::Attic] = true;
+        *lights.get_mut(&Room::Kitchen) = true;
+        lights[Room::Kitchen] = !lights[Room::Kitchen];
+        assert!(lights.get(&Room::Attic));
+        assert_eq!(
+            vec![(Room::Kitchen, &false), (Room::Attic, &true)],
+            lights.iter().collect::<Vec<_>>()
+        );
+
+        for (_, on) in lights.iter_mut() {
+            *on = true;
+        }
+        assert_eq!(RoomMap::from_fn(|_| true), lights);
+    }
+
+    enum_str! {
+        #[set(WindowSet)]
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        Window,
+        (North, "north"),
+        #[cfg(any())]
+        (Skylight, "skylight"),
+        (South, "south"),
+        (East, "east"),
+    }
+
+    #[test]
+    fn test_set() {
+        let mut open = WindowSet::new();
+        assert!(open.is_empty());
+        assert!(open.insert(Window::South));
+        assert!(!open.insert(Window::South));
+        open.insert(Window::East);
+        assert_eq!(0b110, open.bits());
+        assert_eq!(2, open.len());
+        assert!(open.remove(&Window::South));
+        assert!(!open.contains(&Window::South));
+        assert_eq!(vec![Window::East], open.iter().collect::<Vec<_>>());
+
+        let all: WindowSet = [Window::North, Window::South, Window::East]
+            .into_iter()
+            .collect();
+        assert_eq!(WindowSet::all(), all);
+        assert_eq!(WindowSet::all(), WindowSet::from_bits(u64::MAX));
+        assert_eq!(0b111, WindowSet::all().bits());
+        assert_eq!("north,south,east", WindowSet::all().to_string());
+        assert_eq!("", WindowSet::new().to_string());
+    }
+
+    #[test]
+    fn test_cmp_str() {
+        use core::cmp::Ordering;
+
+        assert_eq!(Ordering::Greater, Fruit::Apple.cmp_str(&Fruit::Pineapple));
+        assert_eq!(Ordering::Less, Fruit::Apple.cmp_str(&Fruit::Strawberry));
+        assert_eq!(Ordering::Equal, Fruit::Apple.cmp_str(&Fruit::Apple));
+        assert_eq!(
+            [Fruit::Pineapple, Fruit::Apple, Fruit::Strawberry],
+            Fruit::sorted_variants()
+        );
+        assert_eq!(
+            [Window::East, Window::North, Window::South],
+            Window::sorted_variants()
+        );
+    }
+
+    #[test]
+    fn test_index() {
+        for (i, variant) in Fruit::iter().enumerate() {
+            assert_eq!(i, variant.index());
+            assert_eq!(Some(variant), Fruit::from_index(i));
+        }
+        assert_eq!(None, Fruit::from_index(Fruit::COUNT));
+
+        assert_eq!(1, Market::Fig.index());
+        assert_eq!(Some(Market::Fig), Market::from_index(1));
+    }
+
+    #[test]
+    fn test_try_from_index() {
+        assert_eq!(Ok(Fruit::Apple), Fruit::try_from(0_u8));
+        assert_eq!(Ok(Fruit::Pineapple), Fruit::try_from(1_u16));
+        assert_eq!(Ok(Fruit::Strawberry), Fruit::try_from(2_u32));
+        assert_eq!(Ok(Fruit::Strawberry), Fruit::try_from(2_u64));
+        assert_eq!(Ok(Fruit::Apple), Fruit::try_from(0_usize));
+
+        let err = Fruit::try_from(u64::MAX).unwrap_err();
+        assert_eq!("Fruit", err.name());
+        assert_eq!(u64::MAX, err.index());
+        assert_eq!(3, err.count());
+        assert_eq!(
+            "no Fruit at index 3, expected less than 3",
+            Fruit::try_from(3_u32).unwrap_err().to_string()
+        );
+        assert_eq!(Ok(Market::Fig), Market::try_from(1_usize));
+    }
+
+    #[test]
+    fn test_next_prev() {
+        assert_eq!(Some(Window::South), Window::North.next());
+        assert_eq!(Some(Window::East), Window::South.next());
+        assert_eq!(None, Window::East.next());
+        assert_eq!(None, Window::North.prev());
+        assert_eq!(Some(Window::North), Window::South.prev());
+        assert_eq!(Window::North, Window::East.next_wrapping());
+        assert_eq!(Window::East, Window::North.prev_wrapping());
+        assert_eq!(Window::South, Window::North.next_wrapping());
+
+        enum_str! {
+            Single,
+            (Only, "only"),
+        }
+        assert_eq!(Single::Only, Single::Only.next_wrapping());
+        assert_eq!(Single::Only, Single::Only.prev_wrapping());
+    }
+
+    #[test]
+    fn test_set_from_str() {
+        assert_eq!(Ok(WindowSet::new()), " ".parse());
+        assert_eq!(Ok(WindowSet::from_bits(0b101)), "east ,north".parse());
+        let err = "north,skylight".parse::<WindowSet>().unwrap_err();
+        assert_eq!("Window", err.name());
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    enum Locale {
+        De,
+        Es,
+    }
+
+    enum_str! {
+        #[locale(Locale)]
+        #[case_insensitive]
+        #[trim]
+        #[vis(as_str_locale = pub(crate), from_str_any_locale = pub(crate))]
+        Tint,
+        (Red, "red", aliases = ["crimson"], locale(De = "rot", Es = "rojo")),
+        #[cfg(any())]
+        (Pink, "pink", locale(De = "rosa", Es = "rosa")),
+        (Blue, "blue", default, locale(Es = "azul")),
+        (Gray, "gray", locale(De = "grau")),
+    }
+
+    #[test]
+    fn test_locale() {
+        assert_eq!("rot", Tint::Red.as_str_locale(Locale::De));
+        assert_eq!("rojo", Tint::Red.as_str_locale(Locale::Es));
+        assert_eq!("blue", Tint::Blue.as_str_locale(Locale::De));
+        assert_eq!("gray", Tint::Gray.as_str_locale(Locale::Es));
+        assert_eq!(Tint::Blue, Tint::default());
+
+        assert_eq!(Some(Tint::Red), Tint::from_str_any_locale(" CRIMSON "));
+        assert_eq!(Some(Tint::Blue), Tint::from_str_any_locale(" azul"));
+        assert_eq!(Some(Tint::Gray), Tint::from_str_any_locale("grau"));
+        assert_eq!(None, Tint::from_str_any_locale("rosa"));
+        assert!(Tint::from_str("rot").is_err());
+    }
+
+    enum_str! {
+        #[case_insensitive]
+        Unit,
+        (Meter, "meter", short = ["m"], long = ["metre"]),
+        #[cfg(any())]
+        (Inch, "inch", short = ["in"]),
+        (Gram, "gram", aliases = ["gramme"], short = ["g"]),
+        (Liter, "liter", long = ["litre"]),
+    }
+
+    enum_str! {
+        Reply,
+        (Ready, "ready", 220),
+        #[cfg(any())]
+        (Closing, "closing", 221),
+        (Ok, "ok", 250, default),
+        (Busy, "busy", aliases = ["unavailable"], 450),
+    }
+
+    #[test]
+    fn test_code() {
+        assert_eq!(220, Reply::Ready.code());
+        assert_eq!(250, Reply::Ok.code());
+        assert_eq!(450, Reply::Busy.code());
+        assert_eq!(Some(Reply::Busy), Reply::from_code(450));
+        assert_eq!(None, Reply::from_code(221));
+        assert_eq!(Reply::Busy, "unavailable".parse().unwrap());
+        assert_eq!(Reply::Ok, Reply::default());
+        assert!(Reply::from_str("250").is_err());
+    }
+
+    #[test]
+    fn test_columns() {
+        assert_eq!("m", Unit::Meter.as_short_str());
+        assert_eq!("g", Unit::Gram.as_short_str());
+        assert_eq!("liter", Unit::Liter.as_short_str());
+        assert_eq!("metre", Unit::Meter.as_long_str());
+        assert_eq!("gram", Unit::Gram.as_long_str());
+        assert_eq!("litre", Unit::Liter.as_long_str());
+        assert_eq!(Unit::Meter, "M".parse().unwrap());
+        assert_eq!(Unit::Liter, "Litre".parse().unwrap());
+        assert!(Unit::from_str("in").is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_columns_catch_all() {
+        enum_str! {
+            Size,
+            (Small, "small", short = ["S"]),
+            _ => Custom,
+        }
+        assert_eq!("S", Size::Small.as_short_str());
+        assert_eq!("XL", Size::Custom("XL".into()).as_short_str());
+    }
+
+    #[test]
+    fn test_cfg_variant() {
+        assert_eq!(&[Market::Apple, Market::Fig], Market::VARIANTS);
+        assert_eq!(&["apple", "fig"], Market::VALUES);
+        assert_eq!(2, Market::iter().count());
+        assert_eq!("fig", Market::Fig.to_string());
+        assert!(Market::from_str("durian").is_err());
+        assert!(Market::from_str("durio").is_err());
+        assert_eq!(None, Market::from_name("Durian"));
+        assert_eq!(Market::Fig, Market::from_str("fig").unwrap());
+    }
+
+    enum_str! {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        #[trim]
+        enum {
+            Bread {
+                (Rye, "rye"),
+                (Spelt, "spelt"),
+            }
+            #[case_insensitive]
+            Cheese {
+                (Brie, "brie"),
+                (Gouda, "gouda", default),
+            },
+        }
+    }
+
+    #[test]
+    fn test_block() {
+        assert_eq!(Bread::Rye, Bread::from_str(" rye ").unwrap());
+        assert!(Bread::from_str("RYE").is_err());
+        assert_eq!(Cheese::Brie, Cheese::from_str(" BRIE").unwrap());
+        assert_eq!(Cheese::Gouda, Cheese::default());
+    }
+
+    enum_str! {
+        Coin,
+        /// Both sides are fine.
+        Heads = "heads",
+        #[cfg(any())]
+        Edge = "edge",
+        Tails = "tails",
+    }
+
+    #[cfg(feature = "alloc")]
+    enum_str! {
+        #[case_insensitive]
+        Encoding,
+        Utf8 => "utf-8",
+        Latin1 => "latin-1",
+        _ => Other,
+    }
+
+    enum_str! {
+        Tide,
+        (Low, "low",),
+        (High, "high", aliases = ["flood"])
+    }
+
+    enum_str! {
+        Roast,
+        Light = "light",
+        Dark = "dark"
+    }
+
+    #[test]
+    fn test_trailing_comma() {
+        assert_eq!(Tide::High, Tide::from_str("flood").unwrap());
+        assert_eq!("low", Tide::Low.as_str());
+        assert_eq!(&["light", "dark"], Roast::VALUES);
+    }
+
+    #[test]
+    fn test_shorthand() {
+        assert_eq!(&[Coin::Heads, Coin::Tails], Coin::VARIANTS);
+        assert_eq!("tails", Coin::Tails.as_str());
+        assert!(Coin::from_str("edge").is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_shorthand_catch_all() {
+        assert_eq!(Encoding::Utf8, Encoding::from_str("UTF-8").unwrap());
+        assert_eq!(
+            Encoding::Other(String::from("ascii")),
+            Encoding::from_str("ascii").unwrap()
+        );
+    }
+
+    enum_str! {
+        #[skip(Display, FromStr, AsRef)]
+        Grade,
+        (Pass, "pass"),
+        (Fail, "fail"),
+    }
+
+    impl core::fmt::Display for Grade {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.write_str(&self.as_str().to_uppercase())
+        }
+    }
+
+    impl FromStr for Grade {
+        type Err = ();
+
+        fn from_str(input: &str) -> Result<Self, ()> {
+            Grade::try_from(input.to_lowercase().as_str()).map_err(|_| ())
+        }
+    }
+
+    #[test]
+    fn test_skip() {
+        assert_eq!("PASS", Grade::Pass.to_string());
+        assert_eq!(Ok(Grade::Fail), Grade::from_str("FAIL"));
+        assert!(Grade::try_from("FAIL").is_err());
+        let value: &'static str = Grade::Pass.into();
+        assert_eq!("pass", value);
+    }
+
+    enum_str! {
+        #[rename(as_str = as_label)]
+        Priority,
+        (Low, "low"),
+        (High, "high"),
+    }
+
+    impl Priority {
+        fn as_str(&self) -> &'static str {
+            match self {
+                Priority::Low => "L",
+                Priority::High => "H",
+            }
+        }
+    }
+
+    #[test]
+    fn test_rename_as_str() {
+        const HIGH: &str = Priority::High.as_label();
+        assert_eq!("high", HIGH);
+        assert_eq!("L", Priority::Low.as_str());
+        assert_eq!("low", Priority::Low.to_string());
+        assert_eq!(Priority::High, Priority::from_str("high").unwrap());
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum AppError {
+        Unknown(&'static str),
+        TooLong(usize),
+    }
+
+    impl From<crate::ParseError<Season>> for AppError {
+        fn from(err: crate::ParseError<Season>) -> Self {
+            AppError::Unknown(err.name())
+        }
+    }
+
+    impl AppError {
+        fn too_long(input: &str) -> Self {
+            AppError::TooLong(input.len())
+        }
+    }
+
+    enum_str! {
+        #[error(AppError)]
+        Season,
+        (Spring, "spring"),
+        (Autumn, "autumn"),
+    }
+
+    enum_str! {
+        #[error(AppError, with = AppError::too_long)]
+        Meal,
+        (Lunch, "lunch"),
+    }
+
+    #[test]
+    fn test_custom_error() {
+        assert_eq!(Err(AppError::Unknown("Season")), Season::from_str("winter"));
+        assert_eq!(Err(AppError::Unknown("Season")), Season::try_from("summer"));
+        assert_eq!(Ok(Season::Autumn), Season::from_str("autumn"));
+        assert_eq!(Err(AppError::TooLong(6)), Meal::from_str("dinner"));
+        assert_eq!(Ok(Meal::Lunch), Meal::try_from("lunch"));
+    }
+
+    #[test]
+    fn test_aliases() {
+        assert_eq!(Answer::Yes, Answer::from_str("Y").unwrap());
+        assert_eq!(Answer::Yes, Answer::from_str("TRUE").unwrap());
+        assert_eq!(Answer::No, Answer::from_str("n").unwrap());
+        assert_eq!("yes", Answer::Yes.as_str());
+        assert_eq!("no", Answer::No.to_string());
+    }
+
+    enum_str! {
+        #[trim]
+        Weather,
+        (Sunny, "sunny"),
+        (PartlyCloudy, "partly cloudy"),
+    }
+
+    enum_str! {
+        #[trim(collapse)]
+        #[case_insensitive]
+        Shape,
+        (Circle, "circle"),
+        (RoundedSquare, "rounded square", aliases = ["squircle"]),
+    }
+
+    #[test]
+    fn test_trim() {
+        assert_eq!(Weather::Sunny, Weather::from_str(" sunny\t").unwrap());
+        assert_eq!(
+            Weather::PartlyCloudy,
+            Weather::from_str("partly cloudy ").unwrap()
+        );
+        assert!(Weather::from_str("partly  cloudy").is_err());
+
+        assert_eq!(Shape::Circle, Shape::from_str("CIRCLE ").unwrap());
+        assert_eq!(
+            Shape::RoundedSquare,
+            Shape::from_str(" Rounded \n Square").unwrap()
+        );
+        assert_eq!(Shape::RoundedSquare, Shape::from_str("squircle").unwrap());
+        assert!(Shape::from_str("roundedsquare").is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_trim_error_keeps_input() {
+        assert_eq!(" rainy ", Weather::from_str(" rainy ").unwrap_err().input());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_names() {
+        enum_str! {
+            #[serde(names)]
+            #[trim]
+            Drink,
+            (Tea, "tea"),
+            (Coffee, "Tea-Alternative", aliases = ["joe"]),
+        }
+        assert_eq!(Drink::Tea, serde_json::from_str("\" Tea \"").unwrap());
+        assert_eq!(Drink::Coffee, serde_json::from_str("\"Coffee\"").unwrap());
+        assert_eq!(Drink::Coffee, serde_json::from_str("\"joe\"").unwrap());
+        assert!(serde_json::from_str::<Drink>("\"Water\"").is_err());
+        assert!(Drink::from_str("Coffee").is_err());
+
+        #[cfg(feature = "alloc")]
+        {
+            enum_str! {
+                #[serde(names)]
+                Pet,
+                (Cat, "Dog"),
+                (Dog, "cat"),
+                _ => Other,
+            }
+            assert_eq!(Pet::Cat, serde_json::from_str("\"Dog\"").unwrap());
+            assert_eq!(Pet::Dog, serde_json::from_str("\"cat\"").unwrap());
+            assert_eq!(Pet::Cat, serde_json::from_str("\"Cat\"").unwrap());
+            assert_eq!(
+                Pet::Other("Fish".into()),
+                serde_json::from_str("\"Fish\"").unwrap()
+            );
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    enum_str! {
+        #[serde(module = stencil_str)]
+        #[skip(Deserialize)]
+        #[derive(Debug, Clone, Copy, PartialEq, serde::Deserialize)]
+        Stencil,
+        (Circle, "()"),
+        (Square, "[]"),
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_module() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Drawing {
+            #[serde(with = "stencil_str")]
+            outline: Stencil,
+            fill: Stencil,
+        }
+
+        let drawing = Drawing {
+            outline: Stencil::Square,
+            fill: Stencil::Circle,
+        };
+        let json = r#"{"outline":"[]","fill":"()"}"#;
+        assert_eq!(json, serde_json::to_string(&drawing).unwrap());
+        assert!(serde_json::from_str::<Drawing>(json).is_err());
+
+        let drawing: Drawing = serde_json::from_str(r#"{"outline":"()","fill":"Square"}"#).unwrap();
+        assert_eq!(Stencil::Circle, drawing.outline);
+        assert_eq!(Stencil::Square, drawing.fill);
+        let err =
+            serde_json::from_str::<Drawing>(r#"{"outline":"<>","fill":"Square"}"#).unwrap_err();
+        assert!(err.to_string().contains("unknown Stencil"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_other() {
+        enum_str! {
+            #[serde(other = Unsupported)]
+            Codec,
+            (Gzip, "gzip"),
+            (Unsupported, "unsupported"),
+        }
+        assert_eq!(Codec::Gzip, serde_json::from_str("\"gzip\"").unwrap());
+        assert_eq!(
+            Codec::Unsupported,
+            serde_json::from_str("\"zstd\"").unwrap()
+        );
+        assert_eq!(
+            Codec::Unsupported,
+            serde_json::from_str("\"Gzip\"").unwrap()
+        );
+        assert!(serde_json::from_str::<Codec>("7").is_err());
+        assert!(Codec::from_str("zstd").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        assert_eq!("\"🍓\"", serde_json::to_string(&Fruit::Strawberry).unwrap());
+        let value = serde_json::json!("🍍");
+        assert_eq!(Fruit::Pineapple, serde_json::from_value(value).unwrap());
+
+        assert!(serde_json::from_str::<Fruit>("\"🍌\"").is_err());
+        assert!(serde_json::from_str::<Fruit>("3").is_err());
+    }
+
+    #[cfg(all(feature = "serde", feature = "alloc"))]
+    #[test]
+    fn test_serde_error() {
+        let err = serde_json::from_str::<Fruit>("\"🍌\"").unwrap_err();
+        assert!(err.to_string().contains("unknown Fruit: \"🍌\""));
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn test_json_schema() {
+        let schema = schemars::schema_for!(Fruit);
+        assert_eq!(Some(&serde_json::json!("Fruit")), schema.get("title"));
+        assert_eq!(Some(&serde_json::json!("string")), schema.get("type"));
+        assert_eq!(
+            Some(&serde_json::json!(["🍎", "🍍", "🍓"])),
+            schema.get("enum")
+        );
+
+        let schema = schemars::schema_for!(Answer);
+        assert_eq!(
+            Some(&serde_json::json!(["yes", "y", "true", "no", "n"])),
+            schema.get("enum")
+        );
+    }
+
+    #[cfg(feature = "clap")]
+    #[test]
+    fn test_clap_value_enum() {
+        use clap::{value_parser, Arg, Command, ValueEnum};
+
+        let command =
+            || Command::new("market").arg(Arg::new("answer").value_parser(value_parser!(Answer)));
+
+        let matches = command().get_matches_from(["market", "y"]);
+        assert_eq!(Some(&Answer::Yes), matches.get_one::<Answer>("answer"));
+        assert!(command().try_get_matches_from(["market", "maybe"]).is_err());
+
+        let values: Vec<_> = Answer::value_variants()
+            .iter()
+            .filter_map(|answer| answer.to_possible_value())
+            .map(|value| value.get_name().to_string())
+            .collect();
+        assert_eq!(vec!["yes", "no"], values);
+
+        let command = || {
+            Command::new("market").arg(
+                Arg::new("answer")
+                    .value_parser(value_parser!(Answer))
+                    .ignore_case(true),
+            )
+        };
+        let matches = command().get_matches_from(["market", "YES"]);
+        assert_eq!(Some(&Answer::Yes), matches.get_one::<Answer>("answer"));
+
+        let command = || {
+            Command::new("fetch").arg(Arg::new("protocol").value_parser(value_parser!(Protocol)))
+        };
+        let matches = command().get_matches_from(["fetch", " gopher"]);
+        assert_eq!(
+            Some(&Protocol::Unknown("gopher".into())),
+            matches.get_one::<Protocol>("protocol")
+        );
+    }
+
+    enum_str! {
+        Mime,
+        (Empty, ""),
+        (Html, "text/html"),
+        (Json, "application/json"),
+        (Text, "text/plain", aliases = ["text"]),
+        (Xhtml, "application/xhtml"),
+        (Css, "text/css"),
+        (Csv, "text/csv"),
+    }
+
+    #[test]
+    fn test_length_dispatch() {
+        for mime in Mime::VARIANTS {
+            assert_eq!(Ok(mime.clone()), Mime::from_str(mime.as_str()));
+        }
+        assert_eq!(Ok(Mime::Text), Mime::from_str("text"));
+        assert!(Mime::from_str("text/csx").is_err());
+        assert!(Mime::from_str("sext/css").is_err());
+        assert!(Mime::from_str("application/jsonp").is_err());
+        assert!(Mime::from_str("t").is_err());
+    }
+
+    enum_str! {
+        #[binary_search]
+        #[trim]
+        Element,
+        (Hydrogen, "H"),
+        (Helium, "He"),
+        (Lithium, "Li", aliases = ["lithium"]),
+        #[cfg(any())]
+        (Beryllium, "Be"),
+        (Boron, "B"),
+        (Carbon, "C", aliases = ["carbon", "Cb"]),
+    }
+
+    #[test]
+    fn test_binary_search() {
+        for element in Element::VARIANTS {
+            assert_eq!(Ok(element.clone()), Element::from_str(element.as_str()));
+        }
+        assert_eq!(Ok(Element::Lithium), Element::from_str(" lithium"));
+        assert_eq!(Ok(Element::Carbon), Element::from_str("Cb"));
+        assert!(Element::from_str("Be").is_err());
+        assert!(Element::from_str("he").is_err());
+        assert!(Element::from_str("").is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_hash_map() {
+        enum_str! {
+            #[hash_map]
+            #[trim]
+            Region,
+            (Europe, "EU", aliases = ["europe"]),
+            #[cfg(any())]
+            (Asia, "AS"),
+            (Africa, "AF"),
+            _ => Elsewhere,
+        }
+
+        assert_eq!(Region::Europe, Region::from_str(" europe ").unwrap());
+        assert_eq!(Region::Africa, Region::from_str("AF").unwrap());
+        assert_eq!(
+            Region::Elsewhere("AS".into()),
+            Region::from_str("AS").unwrap()
+        );
+        assert_eq!(Region::Europe, "EU".parse().unwrap());
+    }
+
+    #[cfg(feature = "phf")]
+    enum_str! {
+        #[phf]
+        Airport,
+        (Berlin, "BER", aliases = ["TXL"]),
+        (Lisbon, "LIS"),
+        #[cfg(any())]
+        (Narita, "NRT"),
+        (Tokyo, "HND"),
+    }
+
+    #[cfg(feature = "phf")]
+    #[test]
+    fn test_phf() {
+        assert_eq!(Airport::Berlin, Airport::from_str("BER").unwrap());
+        assert_eq!(Airport::Berlin, Airport::from_str("TXL").unwrap());
+        assert_eq!(Airport::Tokyo, Airport::try_from("HND").unwrap());
+        let err = Airport::from_str("ber").unwrap_err();
+        assert_eq!("Airport", err.name());
+        assert!(Airport::from_str("NRT").is_err());
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    enum_str! {
+        #[nfc]
+        #[case_insensitive]
+        City,
+        (Malmo, "Malm\u{f6}"),
+        (Zurich, "Zu\u{308}rich", aliases = ["Zurich"]),
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn test_nfc() {
+        assert_eq!(City::Malmo, City::from_str("malmo\u{308}").unwrap());
+        assert_eq!(City::Zurich, City::from_str("Z\u{fc}RICH").unwrap());
+        assert_eq!(City::Zurich, City::from_str("zurich").unwrap());
+        assert_eq!(Ok(City::Malmo), City::try_from("Malmo\u{308}".as_bytes()));
+        assert!(City::from_str("Malmo").is_err());
+    }
+
+    #[cfg(feature = "diesel")]
+    #[test]
+    fn test_diesel() {
+        use diesel::dsl::sql;
+        use diesel::sql_types::{Nullable, Text};
+        use diesel::{Connection, IntoSql, RunQueryDsl, SqliteConnection};
+
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+        let fruit: Fruit = diesel::select(Fruit::Apple.into_sql::<Text>())
+            .get_result(&mut conn)
+            .unwrap();
+        assert_eq!(Fruit::Apple, fruit);
+        let fruit: Option<Fruit> =
+            diesel::select((&Fruit::Strawberry).into_sql::<Nullable<Text>>())
+                .get_result(&mut conn)
+                .unwrap();
+        assert_eq!(Some(Fruit::Strawberry), fruit);
+
+        let err = diesel::select(sql::<Text>("'🍌'"))
+            .get_result::<Fruit>(&mut conn)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            diesel::result::Error::DeserializationError(_)
+        ));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn test_postgres() {
+        use bytes::BytesMut;
+        use postgres_types::{FromSql, ToSql, Type};
+
+        let mut buf = BytesMut::new();
+        Fruit::Pineapple
+            .to_sql_checked(&Type::TEXT, &mut buf)
+            .unwrap();
+        assert_eq!(
+            Fruit::Pineapple,
+            Fruit::from_sql(&Type::TEXT, &buf).unwrap()
+        );
+        assert!(Fruit::Apple.to_sql_checked(&Type::INT4, &mut buf).is_err());
+        assert!(<Fruit as FromSql>::accepts(&Type::VARCHAR));
+
+        let err = Fruit::from_sql(&Type::TEXT, "🍌".as_bytes()).unwrap_err();
+        assert_eq!(
+            "unknown Fruit: \"🍌\", expected one of \"🍎\", \"🍍\", \"🍓\"",
+            err.to_string()
+        );
+    }
+
+    #[cfg(feature = "rusqlite")]
+    #[test]
+    fn test_rusqlite() {
+        use rusqlite::Connection;
+
+        let conn = Connection::open_in_memory().unwrap();
+        let fruit: Fruit = conn
+            .query_row("SELECT ?1", [Fruit::Strawberry], |row| row.get(0))
+            .unwrap();
+        assert_eq!(Fruit::Strawberry, fruit);
+
+        let err = conn
+            .query_row("SELECT '🍌'", [], |row| row.get::<_, Fruit>(0))
+            .unwrap_err();
+        assert!(err.to_string().contains("unknown Fruit: \"🍌\""));
+
+        let err = conn
+            .query_row("SELECT 3", [], |row| row.get::<_, Fruit>(0))
+            .unwrap_err();
+        assert!(matches!(err, rusqlite::Error::InvalidColumnType(..)));
+    }
+
+    #[cfg(feature = "pyo3")]
+    #[test]
+    fn test_pyo3() {
+        use pyo3::exceptions::PyValueError;
+        use pyo3::prelude::*;
+
+        Python::attach(|py| {
+            let value = Fruit::Apple.into_pyobject(py).unwrap();
+            assert_eq!("🍎", value.to_str().unwrap());
+            assert_eq!(Fruit::Apple, value.extract::<Fruit>().unwrap());
+
+            let err = "🍌"
+                .into_pyobject(py)
+                .unwrap()
+                .extract::<Fruit>()
+                .unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+            assert!(err
+                .to_string()
+                .contains("expected one of \"🍎\", \"🍍\", \"🍓\""));
+            assert!(3_i32.into_pyobject(py).unwrap().extract::<Fruit>().is_err());
+        });
+    }
+
+    // `JsValue` can only be used on wasm targets, so only the impls are checked here.
+    #[cfg(feature = "wasm")]
+    #[test]
+    fn test_wasm() {
+        use wasm_bindgen::JsValue;
+
+        fn assert_converts<T>()
+        where
+            T: Into<JsValue> + TryFrom<JsValue, Error = JsValue> + for<'a> TryFrom<&'a JsValue>,
+        {
+        }
+        assert_converts::<Fruit>();
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_parse_list() {
+        assert_eq!(Ok(vec![]), Fruit::parse_list(" ", ","));
+        assert_eq!(
+            Ok(vec![Fruit::Strawberry, Fruit::Apple, Fruit::Strawberry]),
+            Fruit::parse_list("🍓 | 🍎|🍓", "|")
+        );
+        assert_eq!(
+            "🍓, 🍎",
+            Fruit::join(&[Fruit::Strawberry, Fruit::Apple], ", ")
+        );
+        assert_eq!("", Fruit::join(&[], ","));
+
+        let err = Fruit::parse_list("🍎 :: 🍍 ::  🍌", "::").unwrap_err();
+        assert_eq!(2, err.index());
+        assert_eq!(17, err.offset());
+        assert_eq!("🍌", err.parse_error().input());
+        assert_eq!(
+            "invalid element 2 at byte 17: unknown Fruit: \"🍌\", expected one of \"🍎\", \"🍍\", \"🍓\"",
+            err.to_string()
+        );
+
+        let err = Fruit::parse_list("🍎,,🍍", ",").unwrap_err();
+        assert_eq!((1, 5), (err.index(), err.offset()));
+
+        let list = Protocol::parse_list("http,gopher", ",").unwrap();
+        assert_eq!("http,gopher", Protocol::join(&list, ","));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_env() {
+        std::env::set_var("ENUM_STR_TEST_FRUIT", "\t🍍 ");
+        assert_eq!(Ok(Fruit::Pineapple), Fruit::from_env("ENUM_STR_TEST_FRUIT"));
+
+        std::env::set_var("ENUM_STR_TEST_FRUIT_INVALID", "🍌");
+        let err = Fruit::from_env("ENUM_STR_TEST_FRUIT_INVALID").unwrap_err();
+        assert!(!err.is_not_present());
+        assert_eq!("ENUM_STR_TEST_FRUIT_INVALID", err.var());
+        assert_eq!(Some("🍌"), err.parse_error().map(|err| err.input()));
+        assert_eq!(
+            "invalid environment variable ENUM_STR_TEST_FRUIT_INVALID: unknown Fruit: \"🍌\", \
+             expected one of \"🍎\", \"🍍\", \"🍓\"",
+            err.to_string()
+        );
+
+        let err = Fruit::from_env("ENUM_STR_TEST_FRUIT_UNSET").unwrap_err();
+        assert!(err.is_not_present());
+        assert_eq!(None, err.parse_error());
+    }
+
+    #[cfg(feature = "sqlx")]
+    #[tokio::test]
+    async fn test_sqlx() {
+        use sqlx::{Connection, Row, SqliteConnection};
+
+        let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+        let fruit: Fruit = sqlx::query_scalar("SELECT ?")
+            .bind(Fruit::Pineapple)
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(Fruit::Pineapple, fruit);
+
+        let row = sqlx::query("SELECT '🍌' AS fruit")
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+        let err = row.try_get::<Fruit, _>("fruit").unwrap_err();
+        assert!(err.to_string().contains("unknown Fruit: \"🍌\""));
+    }
+
+    #[cfg(feature = "strum")]
+    #[test]
+    fn test_strum() {
+        use strum::{EnumCount, IntoEnumIterator, VariantNames};
+
+        fn names<T: VariantNames>() -> &'static [&'static str] {
+            T::VARIANTS
+        }
+
+        fn count<T: EnumCount>() -> usize {
+            T::COUNT
+        }
+
+        let all: Vec<Fruit> = <Fruit as IntoEnumIterator>::iter().rev().collect();
+        assert_eq!(vec![Fruit::Strawberry, Fruit::Pineapple, Fruit::Apple], all);
+        assert_eq!(&["🍎", "🍍", "🍓"], names::<Fruit>());
+        assert_eq!(3, count::<Fruit>());
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn test_arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let data: Vec<u8> = (0..=255).collect();
+        let mut u = Unstructured::new(&data);
+        let mut seen = Vec::new();
+        while !u.is_empty() {
+            let fruit = Fruit::arbitrary(&mut u).unwrap();
+            if !seen.contains(&fruit) {
+                seen.push(fruit);
+            }
+        }
+        assert_eq!(Fruit::COUNT, seen.len());
+    }
+
+    #[cfg(feature = "proptest")]
+    proptest::proptest! {
+        #[test]
+        fn test_proptest(fruit in proptest::arbitrary::any::<Fruit>()) {
+            proptest::prop_assert_eq!(fruit.clone(), Fruit::from_str(fruit.as_str()).unwrap());
+        }
+    }
+
+    #[cfg(feature = "quickcheck")]
+    #[test]
+    fn test_quickcheck() {
+        use quickcheck::{Arbitrary, Gen};
+
+        fn round_trips(fruit: Fruit) -> bool {
+            Fruit::from_str(fruit.as_str()) == Ok(fruit)
+        }
+        quickcheck::quickcheck(round_trips as fn(Fruit) -> bool);
+
+        let mut gen = Gen::new(10);
+        assert!(Fruit::VARIANTS.contains(&Fruit::arbitrary(&mut gen)));
+        assert_eq!(
+            std::vec![Fruit::Apple, Fruit::Pineapple],
+            Fruit::Strawberry.shrink().collect::<std::vec::Vec<_>>()
+        );
+        assert_eq!(0, Fruit::Apple.shrink().count());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_rand() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let fruits: Vec<Fruit> = (0..100).map(|_| rng.random()).collect();
+        for fruit in Fruit::iter() {
+            assert!(fruits.contains(&fruit));
+        }
+    }
+
+    #[cfg(feature = "nom")]
+    enum_str! {
+        #[rename(as_str = as_code, parse = parse_code)]
+        #[vis(parse = pub(crate))]
+        Verb,
+        (Get, "GET"),
+        (Put, "PUT"),
+    }
+
+    #[cfg(feature = "nom")]
+    impl Verb {
+        fn parse(input: &str) -> Option<Self> {
+            input.parse().ok()
+        }
+    }
+
+    #[cfg(feature = "nom")]
+    #[test]
+    fn test_nom() {
+        use nom::Parser;
+
+        assert_eq!(Ok((" 1", Operator::GreaterEqual)), Operator::parse(">= 1"));
+        assert!(Operator::parse("= 1").is_err());
+
+        let mut pair = (
+            Operator::parse,
+            nom::character::complete::space1,
+            Fruit::parse,
+        );
+        assert_eq!(
+            Ok(("", (Operator::Shift, " ", Fruit::Apple))),
+            pair.parse(">> 🍎")
+        );
+
+        assert_eq!(Ok((" /", Verb::Put)), Verb::parse_code("PUT /"));
+        assert_eq!(Some(Verb::Get), Verb::parse("GET"));
+        assert_eq!("GET", Verb::Get.as_code());
+    }
+
+    #[cfg(feature = "winnow")]
+    #[test]
+    fn test_winnow() {
+        use winnow::error::ContextError;
+        use winnow::prelude::*;
+
+        let mut input = ">= 1";
+        assert_eq!(
+            Ok::<_, ContextError>(Operator::GreaterEqual),
+            Operator::parse_next(&mut input)
+        );
+        assert_eq!(" 1", input);
+
+        let mut pair = (Operator::parse_next, " ", Fruit::parse_next);
+        let parsed: Result<_, ContextError> = pair.parse_peek(">> 🍎!");
+        assert_eq!(Ok(("!", (Operator::Shift, " ", Fruit::Apple))), parsed);
+        assert!(Operator::parse_next::<ContextError>(&mut "=").is_err());
+    }
+
+    #[test]
+    fn test_custom_derive() {
+        let level = Level::High;
+        let copy = level;
+        assert_eq!(level, copy);
+        assert!(Level::Low < Level::High);
+        assert_eq!("high", copy.as_str());
+    }
+
+    #[test]
+    fn test_pub_visibility() {
+        assert_eq!("red", public::Color::Red.as_str());
+        assert_eq!(
+            public::Color::Green,
+            public::Color::from_str("green").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_method_visibility() {
+        assert_eq!("dark", public::Shade::Dark.as_str());
+        assert_eq!(&["light", "dark"], public::Shade::VALUES);
+        assert!(matches!(
+            public::shade_named("Light"),
+            Some(public::Shade::Light)
+        ));
     }
 }
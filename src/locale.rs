@@ -0,0 +1,90 @@
+//! The methods generated for enums marked `#[locale(Type)]`, whose variants give their value
+//! in other locales with `locale(...)`.
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_locale {
+    ([$($opt:tt)*] $($rest:tt)*) => {
+        $crate::__enum_str_locale! { @find [$($opt)*] [$($opt)*] $($rest)* }
+    };
+
+    (
+        @find [{locale($locale:ident)} $($_opt:tt)*] [$($opt:tt)*] $vis:vis $name:ident, [],
+        $(#[cfg $cfg:tt] ($key:ident, $value:expr, [$($flag:tt)*])),*
+    ) => {
+        #[allow(dead_code)]
+        impl $name {
+            $crate::__enum_str_item! { as_str_locale, [$($opt)*], $vis,
+                { /// Returns the value of the variant in `locale`, or its value if it has none there.
+                } { fn } {
+                    (&self, locale: $locale) -> &'static str {
+                        match self {
+                            $(
+                                #[cfg $cfg]
+                                &$name::$key => $crate::__enum_str_locale! { @value locale, $locale, $value, $($flag)* },
+                            )*
+                        }
+                    }
+                }
+            }
+
+            $crate::__enum_str_item! { from_str_any_locale, [$($opt)*], $vis,
+                { /// Parses `value` like `from_str`, and then as the value of a variant in any
+                  /// locale, compared exactly.
+                } { fn } {
+                    (value: &str) -> ::core::option::Option<Self> {
+                        let val = $crate::__enum_str_if! { trim, [$($opt)*], { value.trim() }, { value } };
+                        if let ::core::option::Option::Some(variant) = $name::__enum_str_variant(val) {
+                            return ::core::option::Option::Some(variant);
+                        }
+                        $(
+                            #[cfg $cfg]
+                            if $crate::__enum_str_locale! { @any val, $($flag)* } {
+                                return ::core::option::Option::Some($name::$key);
+                            }
+                        )*
+                        ::core::option::Option::None
+                    }
+                }
+            }
+        }
+    };
+    (@find [{locale($locale:ident)} $($_opt:tt)*] $opts:tt $vis:vis $name:ident, [$other:ident], $($variant:tt)*) => {
+        ::core::compile_error!("`#[locale(...)]` cannot be used on an enum with a `_ =>` catch-all");
+    };
+    (@find [$_skip:tt $($opt:tt)*] $($rest:tt)*) => { $crate::__enum_str_locale! { @find [$($opt)*] $($rest)* } };
+    (
+        @find [] $opts:tt $vis:vis $name:ident, $other:tt,
+        $(#[cfg $cfg:tt] ($key:ident, $value:expr, [$($flag:tt)*])),*
+    ) => {
+        $($crate::__enum_str_locale! { @check $name, $key, $($flag)* })*
+    };
+
+    (@value $locale:ident, $ty:ident, $value:expr, {locale($($l:ident = $v:expr),* $(,)?)} $($rest:tt)*) => {{
+        #[allow(unreachable_patterns)]
+        let value = match $locale {
+            $($ty::$l => $v,)*
+            _ => $value,
+        };
+        value
+    }};
+    (@value $locale:ident, $ty:ident, $value:expr, {$($_flag:tt)*} $($rest:tt)*) => {
+        $crate::__enum_str_locale! { @value $locale, $ty, $value, $($rest)* }
+    };
+    (@value $locale:ident, $ty:ident, $value:expr,) => { $value };
+
+    (@any $val:ident, {locale($($l:ident = $v:expr),* $(,)?)} $($rest:tt)*) => { false $(|| $val == $v)* };
+    (@any $val:ident, {$($_flag:tt)*} $($rest:tt)*) => { $crate::__enum_str_locale! { @any $val, $($rest)* } };
+    (@any $val:ident,) => { false };
+
+    (@check $name:ident, $key:ident, {locale($($_l:tt)*)} $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "`", ::core::stringify!($name), "::", ::core::stringify!($key),
+            "` has values per locale, which need a `#[locale(Type)]` on the enum"
+        ));
+    };
+    (@check $name:ident, $key:ident, {$($_flag:tt)*} $($rest:tt)*) => {
+        $crate::__enum_str_locale! { @check $name, $key, $($rest)* }
+    };
+    (@check $name:ident, $key:ident,) => {};
+}
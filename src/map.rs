@@ -0,0 +1,68 @@
+//! The array-backed map type generated for enums marked `#[map(Name)]`.
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_map {
+    ([{map($map:ident)} $($_opt:tt)*] $vis:vis $name:ident, [], $(#[cfg $cfg:tt] $key:ident),*) => {
+        #[doc = ::core::concat!("A map with one value for every variant of [`", ::core::stringify!($name), "`].")]
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        $vis struct $map<V> {
+            values: [V; $name::COUNT],
+        }
+
+        #[allow(dead_code)]
+        impl<V> $map<V> {
+            /// Creates a map holding `f(variant)` for every variant.
+            $vis fn from_fn(mut f: impl ::core::ops::FnMut($name) -> V) -> Self {
+                $map {
+                    values: [$(#[cfg $cfg] f($name::$key)),*],
+                }
+            }
+
+            /// Returns the value of `key`.
+            $vis fn get(&self, key: &$name) -> &V {
+                &self.values[key.__enum_str_index()]
+            }
+
+            /// Returns the value of `key` mutably.
+            $vis fn get_mut(&mut self, key: &$name) -> &mut V {
+                &mut self.values[key.__enum_str_index()]
+            }
+
+            /// Iterates over the variants and their values in declaration order.
+            $vis fn iter(&self) -> impl ::core::iter::Iterator<Item = ($name, &V)> {
+                $name::iter().zip(self.values.iter())
+            }
+
+            /// Iterates over the variants and their values in declaration order, mutably.
+            $vis fn iter_mut(&mut self) -> impl ::core::iter::Iterator<Item = ($name, &mut V)> {
+                $name::iter().zip(self.values.iter_mut())
+            }
+        }
+
+        impl<V: ::core::default::Default> ::core::default::Default for $map<V> {
+            fn default() -> Self {
+                $map::from_fn(|_| V::default())
+            }
+        }
+
+        impl<V> ::core::ops::Index<$name> for $map<V> {
+            type Output = V;
+
+            fn index(&self, key: $name) -> &V {
+                self.get(&key)
+            }
+        }
+
+        impl<V> ::core::ops::IndexMut<$name> for $map<V> {
+            fn index_mut(&mut self, key: $name) -> &mut V {
+                self.get_mut(&key)
+            }
+        }
+    };
+    ([{map($map:ident)} $($_opt:tt)*] $vis:vis $name:ident, [$other:ident], $($rest:tt)*) => {
+        ::core::compile_error!("`#[map(...)]` cannot be used on an enum with a `_ =>` catch-all");
+    };
+    ([$_skip:tt $($opt:tt)*] $($rest:tt)*) => { $crate::__enum_str_map! { [$($opt)*] $($rest)* } };
+    ([] $($rest:tt)*) => {};
+}
@@ -0,0 +1,69 @@
+//! The name to value mapping of an enum, returned by the generated `mapping` function.
+
+use core::fmt;
+
+/// The name and string value of every variant of an enum, in declaration order.
+///
+/// With the `serde` feature it serializes as a map from names to values, e.g. to list the
+/// accepted values of an enum in a JSON endpoint.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Mapping {
+    entries: &'static [(&'static str, &'static str)],
+}
+
+impl Mapping {
+    /// Creates a mapping from `(name, value)` pairs.
+    pub const fn new(entries: &'static [(&'static str, &'static str)]) -> Self {
+        Mapping { entries }
+    }
+
+    /// The `(name, value)` pairs of the mapping.
+    pub const fn entries(&self) -> &'static [(&'static str, &'static str)] {
+        self.entries
+    }
+
+    /// Number of variants in the mapping.
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the mapping has no variants.
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The value of the variant called `name`.
+    pub fn get(&self, name: &str) -> Option<&'static str> {
+        self.entries
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|&(_, value)| value)
+    }
+
+    /// Iterates over the `(name, value)` pairs in declaration order.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &'static str)> {
+        self.entries.iter().copied()
+    }
+}
+
+impl IntoIterator for Mapping {
+    type Item = (&'static str, &'static str);
+    type IntoIter = core::iter::Copied<core::slice::Iter<'static, (&'static str, &'static str)>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter().copied()
+    }
+}
+
+impl fmt::Debug for Mapping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Mapping {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
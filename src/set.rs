@@ -0,0 +1,132 @@
+//! The bitset type generated for enums marked `#[set(Name)]`.
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_str_set {
+    ([{set($set:ident, $int:ident)} $($_opt:tt)*] $vis:vis $name:ident, []) => {
+        #[doc = ::core::concat!(
+            "A set of [`", ::core::stringify!($name), "`] variants, stored as the bits of a `",
+            ::core::stringify!($int), "`.\n\nIt parses from and formats as the values separated by commas."
+        )]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        $vis struct $set($int);
+
+        const _: () = ::core::assert!(
+            $name::COUNT <= <$int>::BITS as usize,
+            ::core::concat!("`", ::core::stringify!($name), "` has more variants than `", ::core::stringify!($int), "` has bits"),
+        );
+
+        #[allow(dead_code)]
+        impl $set {
+            /// Creates an empty set.
+            $vis const fn new() -> Self {
+                $set(0)
+            }
+
+            /// Creates a set from its bits, ignoring those that belong to no variant.
+            $vis const fn from_bits(bits: $int) -> Self {
+                $set(bits & $set::all().0)
+            }
+
+            /// Creates a set with every variant.
+            $vis const fn all() -> Self {
+                if $name::COUNT == 0 {
+                    $set(0)
+                } else {
+                    $set((((1 as $int) << ($name::COUNT - 1)) << 1).wrapping_sub(1))
+                }
+            }
+
+            /// Returns the bits of the set, where the bit of a variant is its declaration order.
+            $vis const fn bits(&self) -> $int {
+                self.0
+            }
+
+            const fn bit(variant: &$name) -> $int {
+                1 << variant.__enum_str_index()
+            }
+
+            /// Adds `variant`, returning whether it was missing.
+            $vis fn insert(&mut self, variant: $name) -> bool {
+                let missing = !self.contains(&variant);
+                self.0 |= $set::bit(&variant);
+                missing
+            }
+
+            /// Removes `variant`, returning whether it was present.
+            $vis fn remove(&mut self, variant: &$name) -> bool {
+                let present = self.contains(variant);
+                self.0 &= !$set::bit(variant);
+                present
+            }
+
+            /// Returns whether the set contains `variant`.
+            $vis const fn contains(&self, variant: &$name) -> bool {
+                self.0 & $set::bit(variant) != 0
+            }
+
+            /// Returns the number of variants in the set.
+            $vis const fn len(&self) -> usize {
+                self.0.count_ones() as usize
+            }
+
+            /// Returns whether the set is empty.
+            $vis const fn is_empty(&self) -> bool {
+                self.0 == 0
+            }
+
+            /// Iterates over the variants in the set in declaration order.
+            $vis fn iter(&self) -> impl ::core::iter::Iterator<Item = $name> {
+                let set = *self;
+                $name::iter().filter(move |variant| set.contains(variant))
+            }
+        }
+
+        impl ::core::iter::FromIterator<$name> for $set {
+            fn from_iter<I: ::core::iter::IntoIterator<Item = $name>>(iter: I) -> Self {
+                let mut set = $set::new();
+                ::core::iter::Extend::extend(&mut set, iter);
+                set
+            }
+        }
+
+        impl ::core::iter::Extend<$name> for $set {
+            fn extend<I: ::core::iter::IntoIterator<Item = $name>>(&mut self, iter: I) {
+                for variant in iter {
+                    self.insert(variant);
+                }
+            }
+        }
+
+        impl ::core::fmt::Display for $set {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                for (i, variant) in self.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(",")?;
+                    }
+                    f.write_str(variant.__enum_str_as_str())?;
+                }
+                ::core::result::Result::Ok(())
+            }
+        }
+
+        impl ::core::str::FromStr for $set {
+            type Err = $crate::ParseError<$name>;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, $crate::ParseError<$name>> {
+                let mut set = $set::new();
+                if !s.trim().is_empty() {
+                    for value in s.split(',') {
+                        set.insert($name::__enum_str_from_str(value.trim())?);
+                    }
+                }
+                ::core::result::Result::Ok(set)
+            }
+        }
+    };
+    ([{set($set:ident, $int:ident)} $($_opt:tt)*] $vis:vis $name:ident, [$other:ident]) => {
+        ::core::compile_error!("`#[set(...)]` cannot be used on an enum with a `_ =>` catch-all");
+    };
+    ([$_skip:tt $($opt:tt)*] $($rest:tt)*) => { $crate::__enum_str_set! { [$($opt)*] $($rest)* } };
+    ([] $($rest:tt)*) => {};
+}
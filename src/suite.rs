@@ -0,0 +1,102 @@
+//! The `enum_str_tests!` macro, which generates tests for enums made with `enum_str!`.
+
+/// Generates a `#[cfg(test)]` module named `enum_str_tests` that checks the string mapping of
+/// each listed enum.
+///
+/// For every variant, the tests assert that its value parses back to it and that `Display`
+/// prints the value. They also check that a string matching no value is rejected, or parsed
+/// into the `_ =>` catch-all. The tests only use the public surface of the enums: `as_str`,
+/// `VARIANTS`, `VALUES`, `name`, `FromStr` and `Display`, so the macro can be invoked anywhere
+/// those are visible, including an integration test. The enums also have to implement
+/// `PartialEq`, and are named relative to the module the macro is invoked in. Each enum gets
+/// its own submodule, so the tests are reported as `enum_str_tests::Fruit::round_trip` and so
+/// on.
+///
+/// An enum whose `as_str` is renamed with `#[rename(as_str = ...)]` is listed with the same
+/// rename, as in `enum_str_tests!(Status(as_str = as_code))`.
+/// ```
+/// use enum_str::{enum_str, enum_str_tests};
+///
+/// enum_str! {
+///     Fruit,
+///     (Apple, "🍎"),
+///     (Pineapple, "🍍"),
+/// }
+///
+/// enum_str! {
+///     Vegetable,
+///     (Carrot, "🥕"),
+///     (Corn, "🌽"),
+/// }
+///
+/// enum_str_tests!(Fruit, Vegetable);
+/// ```
+#[macro_export]
+macro_rules! enum_str_tests {
+    (@suite $name:ident, []) => {
+        $crate::enum_str_tests! { @suite $name, [as_str] }
+    };
+    (@suite $name:ident, [$as_str:ident]) => {
+        #[allow(non_snake_case)]
+        mod $name {
+            extern crate std;
+
+            use super::super::$name as Enum;
+
+            fn parse(value: &str) -> std::option::Option<Enum> {
+                <Enum as std::str::FromStr>::from_str(value).ok()
+            }
+
+            #[test]
+            fn round_trip() {
+                for variant in Enum::VARIANTS {
+                    let value = variant.$as_str();
+                    std::assert!(
+                        parse(value).as_ref() == std::option::Option::Some(variant),
+                        "{:?} does not parse back to `{}::{}`",
+                        value,
+                        std::stringify!($name),
+                        variant.name(),
+                    );
+                }
+            }
+
+            #[test]
+            fn display() {
+                for variant in Enum::VARIANTS {
+                    std::assert_eq!(
+                        variant.$as_str(),
+                        std::string::ToString::to_string(variant),
+                        "`{}::{}` is displayed differently from its value",
+                        std::stringify!($name),
+                        variant.name(),
+                    );
+                }
+            }
+
+            #[test]
+            fn rejects_unknown() {
+                // Longer than every value and framed by NULs, which no normalization removes.
+                let unknown = std::format!("\0{}\0", Enum::VALUES.concat());
+                if let std::option::Option::Some(variant) = parse(&unknown) {
+                    std::assert!(
+                        !Enum::VARIANTS.contains(&variant),
+                        "{:?} parses as `{}::{}`",
+                        unknown,
+                        std::stringify!($name),
+                        variant.name(),
+                    );
+                }
+            }
+        }
+    };
+
+    ($($name:ident $((as_str = $as_str:ident))?),+ $(,)?) => {
+        #[cfg(test)]
+        mod enum_str_tests {
+            $(
+                $crate::enum_str_tests! { @suite $name, [$($as_str)?] }
+            )+
+        }
+    };
+}